@@ -8,7 +8,6 @@ fn main() {
     // Set the straight dotted line
     let mut line = Plot2D::new();
     line.coordinates = (0..11)
-        .into_iter()
         .map(|i| (f64::from(i), 2.0 * PI * f64::from(i)).into())
         .collect();
     line.add_key(PlotKey::Custom(String::from("dashed")));
@@ -42,8 +41,8 @@ fn main() {
     axis.set_title("Slope is $2\\pi$");
     axis.set_x_label("Radius~[m]");
     axis.set_y_label("Circumference~[m]");
-    axis.plots.push(line);
-    axis.plots.push(points);
+    axis.plots.push(line.into());
+    axis.plots.push(points.into());
     axis.add_key(AxisKey::Custom(String::from("legend entries={fit,data}")));
     axis.add_key(AxisKey::Custom(String::from("legend pos=north west")));
 
@@ -1,6 +1,9 @@
-use pgfplots::axis::{
-    plot::{Plot2D, PlotKey},
-    Axis, AxisKey,
+use pgfplots::{
+    axis::{
+        plot::{Plot2D, PlotKey},
+        Axis, AxisKey,
+    },
+    color::{Color, NamedColor},
 };
 
 fn main() {
@@ -19,12 +22,16 @@ fn main() {
 
     let mut plot = Plot2D::new();
     plot.coordinates = vertices.into_iter().map(|v| v.into()).collect();
-    plot.add_key(PlotKey::Custom(String::from("fill=gray!20")));
+    plot.add_key(PlotKey::Fill(Color::mix(
+        NamedColor::Gray,
+        20.0,
+        NamedColor::White,
+    )));
 
     let mut axis = Axis::new();
     axis.set_title("Kloch Snowflake");
-    axis.plots.push(plot);
-    axis.add_key(AxisKey::Custom(String::from("hide axis")));
+    axis.plots.push(plot.into());
+    axis.add_key(AxisKey::HideAxis);
 
     #[cfg(feature = "inclusive")]
     axis.show().unwrap();
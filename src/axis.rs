@@ -1,8 +1,23 @@
-use crate::{axis::plot::Plot2D, Picture};
-use std::fmt;
+use crate::{
+    axis::plot::{fill_between::FillBetween, Plot, PlotKey, Type2D},
+    color::Color,
+    compiler::Compiler,
+    length::Length,
+    number::NumberFormat,
+    preamble::Preamble,
+    ShowPdfError, Standalone,
+};
+use std::{fmt, io, path::Path};
 
+#[cfg(feature = "png")]
+use crate::compiler::Background;
 #[cfg(feature = "inclusive")]
-use crate::ShowPdfError;
+use crate::compiler::Tectonic;
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{axis::plot::Plot2D, Picture};
 
 /// Plot inside an [`Axis`] environment.
 pub mod plot;
@@ -28,10 +43,158 @@ pub enum AxisKey {
     XLabel(String),
     /// Control the label of the *y* axis.
     YLabel(String),
+    /// Control the scaling of the *z* axis.
+    ZMode(Scale),
+    /// Control the label of the *z* axis.
+    ZLabel(String),
+    /// Control the viewing angle of three dimensional axes. Both angles are in
+    /// degrees.
+    View { azimuth: f64, elevation: f64 },
+    /// Control the colormap used by plots that map values to colors e.g.
+    /// surfaces and matrix plots.
+    Colormap(Colormap),
+    /// Show a colorbar with the colormap next to the axis.
+    Colorbar(Colorbar),
+    /// Value mapped to the lowest color of the colormap. By default, this is
+    /// the minimum value of all plots in the axis.
+    PointMetaMin(f64),
+    /// Value mapped to the highest color of the colormap. By default, this is
+    /// the maximum value of all plots in the axis.
+    PointMetaMax(f64),
+    /// Stack the plots of the axis on top of each other. Use [`Axis::stack`]
+    /// to also check that the plots can be stacked.
+    Stack(Stack),
+    /// Lower limit of the *x* axis.
+    XMin(f64),
+    /// Upper limit of the *x* axis.
+    XMax(f64),
+    /// Lower limit of the *y* axis.
+    YMin(f64),
+    /// Upper limit of the *y* axis.
+    YMax(f64),
+    /// Lower limit of the *z* axis.
+    ZMin(f64),
+    /// Upper limit of the *z* axis.
+    ZMax(f64),
+    /// Positions of the major ticks on the *x* axis. An empty list removes
+    /// the ticks.
+    XTick(Vec<f64>),
+    /// Positions of the major ticks on the *y* axis. An empty list removes
+    /// the ticks.
+    YTick(Vec<f64>),
+    /// Positions of the major ticks on the *z* axis. An empty list removes
+    /// the ticks.
+    ZTick(Vec<f64>),
+    /// Labels of the major ticks on the *x* axis, in the same order as the
+    /// ticks. Each label is enclosed in braces, so it can contain commas and
+    /// equal signs. Labels with unbalanced braces are written literally.
+    XTickLabels(Vec<String>),
+    /// Labels of the major ticks on the *y* axis, in the same order as the
+    /// ticks. Each label is enclosed in braces, so it can contain commas and
+    /// equal signs. Labels with unbalanced braces are written literally.
+    YTickLabels(Vec<String>),
+    /// Labels of the major ticks on the *z* axis, in the same order as the
+    /// ticks. Each label is enclosed in braces, so it can contain commas and
+    /// equal signs. Labels with unbalanced braces are written literally.
+    ZTickLabels(Vec<String>),
+    /// Number of minor ticks between two major ticks of the *x* axis.
+    MinorXTickNum(usize),
+    /// Number of minor ticks between two major ticks of the *y* axis.
+    MinorYTickNum(usize),
+    /// Number of minor ticks between two major ticks of the *z* axis.
+    MinorZTickNum(usize),
+    /// Control how the numbers of the tick labels of the *x* axis are
+    /// written.
+    XTickLabelFormat(TickLabelFormat),
+    /// Control how the numbers of the tick labels of the *y* axis are
+    /// written.
+    YTickLabelFormat(TickLabelFormat),
+    /// Control how the numbers of the tick labels of the *z* axis are
+    /// written.
+    ZTickLabelFormat(TickLabelFormat),
+    /// Rotate the tick labels of the *x* axis counterclockwise by the given
+    /// angle in degrees. Rotated labels are anchored at their end, so they
+    /// line up with their ticks.
+    XTickLabelRotation(f64),
+    /// Rotate the tick labels of the *y* axis counterclockwise by the given
+    /// angle in degrees.
+    YTickLabelRotation(f64),
+    /// Control the position of the legend.
+    LegendPos(LegendPos),
+    /// Number of columns of the legend. By default, all the entries are in a
+    /// single column.
+    LegendColumns(usize),
+    /// Style of the legend e.g. `draw=none, font=\small`. This is appended to
+    /// any previous style, including the one set by [`AxisKey::LegendPos`].
+    LegendStyle(String),
+    /// Reverse the order of the entries in the legend.
+    ReverseLegend,
+    /// Control which grid lines are drawn at the ticks.
+    Grid(Grid),
+    /// Style of all the grid lines.
+    GridStyle(LineStyle),
+    /// Style of the grid lines at the major ticks. This takes precedence over
+    /// [`AxisKey::GridStyle`].
+    MajorGridStyle(LineStyle),
+    /// Style of the grid lines at the minor ticks. This takes precedence over
+    /// [`AxisKey::GridStyle`].
+    MinorGridStyle(LineStyle),
+    /// Control where the axis lines are drawn. The ticks and labels are moved
+    /// along with the axis lines.
+    AxisLines(AxisLines),
+    /// Control where the line of the *x* axis is drawn. Only the line is
+    /// moved; the ticks and labels of the axis stay where they are.
+    XAxisLine(XAxisLine),
+    /// Control where the line of the *y* axis is drawn. Only the line is
+    /// moved; the ticks and labels of the axis stay where they are.
+    YAxisLine(YAxisLine),
+    /// Style of the axis lines, including the frame around the axis.
+    AxisLineStyle(LineStyle),
+    /// Draw the axis lines, ticks and grid on top of the plots instead of
+    /// below them.
+    AxisOnTop,
+    /// Hide the axis lines, ticks, labels and grid. The plots are still drawn
+    /// and still determine the limits of the axis.
+    HideAxis,
+    /// Hide the line, ticks and labels of the *x* axis.
+    HideXAxis,
+    /// Hide the line, ticks and labels of the *y* axis.
+    HideYAxis,
+    /// Hide the line, ticks and labels of the *z* axis.
+    HideZAxis,
+    /// Width of the axis, including its labels unless
+    /// [`AxisKey::ScaleOnlyAxis`] is set.
+    Width(Length),
+    /// Height of the axis, including its labels unless
+    /// [`AxisKey::ScaleOnlyAxis`] is set.
+    Height(Length),
+    /// Apply [`AxisKey::Width`] and [`AxisKey::Height`] to the axis area only
+    /// i.e. without the ticks, labels, title and legend. This gives exact
+    /// dimensions, which is useful to align several figures.
+    ScaleOnlyAxis,
+    /// Control the relative scaling of the axes.
+    Aspect(Aspect),
+    /// Control whether the limits of the axes are enlarged beyond the range
+    /// of the plots, so that the plots do not touch the axis lines.
+    EnlargeLimits(bool),
+    /// Reverse the direction of the *x* axis, which then grows to the left.
+    ReverseXAxis,
+    /// Reverse the direction of the *y* axis, which then grows downwards.
+    ReverseYAxis,
+    /// Reverse the direction of the *z* axis.
+    ReverseZAxis,
 }
 
 impl fmt::Display for AxisKey {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl AxisKey {
+    /// Same as [`fmt::Display`], but the numbers (e.g. limits and ticks) are
+    /// written with a custom [`NumberFormat`].
+    pub(crate) fn fmt_with(&self, f: &mut fmt::Formatter<'_>, format: NumberFormat) -> fmt::Result {
         match self {
             AxisKey::Custom(key) => write!(f, "{key}"),
             AxisKey::XMode(value) => write!(f, "xmode={value}"),
@@ -39,8 +202,145 @@ impl fmt::Display for AxisKey {
             AxisKey::Title(value) => write!(f, "title={{{value}}}"),
             AxisKey::XLabel(value) => write!(f, "xlabel={{{value}}}"),
             AxisKey::YLabel(value) => write!(f, "ylabel={{{value}}}"),
+            AxisKey::ZMode(value) => write!(f, "zmode={value}"),
+            AxisKey::ZLabel(value) => write!(f, "zlabel={{{value}}}"),
+            AxisKey::View { azimuth, elevation } => write!(f, "view={{{azimuth}}}{{{elevation}}}"),
+            AxisKey::Colormap(value) => write!(f, "{value}"),
+            AxisKey::Colorbar(value) => write!(f, "{value}"),
+            AxisKey::PointMetaMin(value) => {
+                write!(f, "point meta min={}", format.display(*value))
+            }
+            AxisKey::PointMetaMax(value) => {
+                write!(f, "point meta max={}", format.display(*value))
+            }
+            AxisKey::Stack(value) => write!(f, "{value}"),
+            AxisKey::XMin(value) => write!(f, "xmin={}", format.display(*value)),
+            AxisKey::XMax(value) => write!(f, "xmax={}", format.display(*value)),
+            AxisKey::YMin(value) => write!(f, "ymin={}", format.display(*value)),
+            AxisKey::YMax(value) => write!(f, "ymax={}", format.display(*value)),
+            AxisKey::ZMin(value) => write!(f, "zmin={}", format.display(*value)),
+            AxisKey::ZMax(value) => write!(f, "zmax={}", format.display(*value)),
+            AxisKey::XTick(value) => write!(f, "xtick={}", TickList(value, format)),
+            AxisKey::YTick(value) => write!(f, "ytick={}", TickList(value, format)),
+            AxisKey::ZTick(value) => write!(f, "ztick={}", TickList(value, format)),
+            AxisKey::XTickLabels(value) => write!(f, "xticklabels={}", LabelList(value)),
+            AxisKey::YTickLabels(value) => write!(f, "yticklabels={}", LabelList(value)),
+            AxisKey::ZTickLabels(value) => write!(f, "zticklabels={}", LabelList(value)),
+            AxisKey::MinorXTickNum(value) => write!(f, "minor x tick num={value}"),
+            AxisKey::MinorYTickNum(value) => write!(f, "minor y tick num={value}"),
+            AxisKey::MinorZTickNum(value) => write!(f, "minor z tick num={value}"),
+            AxisKey::XTickLabelFormat(value) => write!(f, "xticklabel style={{{value}}}"),
+            AxisKey::YTickLabelFormat(value) => write!(f, "yticklabel style={{{value}}}"),
+            AxisKey::ZTickLabelFormat(value) => write!(f, "zticklabel style={{{value}}}"),
+            AxisKey::XTickLabelRotation(value) => {
+                write!(f, "xticklabel style={{rotate={value}")?;
+                // The default anchor (north) would center the rotated labels
+                // on their ticks.
+                if *value > 0.0 {
+                    write!(f, ", anchor=east")?;
+                } else if *value < 0.0 {
+                    write!(f, ", anchor=west")?;
+                }
+                write!(f, "}}")
+            }
+            AxisKey::YTickLabelRotation(value) => write!(f, "yticklabel style={{rotate={value}}}"),
+            AxisKey::LegendPos(value) => write!(f, "{value}"),
+            AxisKey::LegendColumns(value) => write!(f, "legend columns={value}"),
+            AxisKey::LegendStyle(value) => write!(f, "legend style={{{value}}}"),
+            AxisKey::ReverseLegend => write!(f, "reverse legend"),
+            AxisKey::Grid(value) => write!(f, "grid={value}"),
+            AxisKey::GridStyle(value) => write!(f, "grid style={{{value}}}"),
+            AxisKey::MajorGridStyle(value) => write!(f, "major grid style={{{value}}}"),
+            AxisKey::MinorGridStyle(value) => write!(f, "minor grid style={{{value}}}"),
+            AxisKey::AxisLines(value) => write!(f, "axis lines={value}"),
+            AxisKey::XAxisLine(value) => write!(f, "axis x line*={value}"),
+            AxisKey::YAxisLine(value) => write!(f, "axis y line*={value}"),
+            AxisKey::AxisLineStyle(value) => write!(f, "axis line style={{{value}}}"),
+            AxisKey::AxisOnTop => write!(f, "axis on top"),
+            AxisKey::HideAxis => write!(f, "hide axis"),
+            AxisKey::HideXAxis => write!(f, "hide x axis"),
+            AxisKey::HideYAxis => write!(f, "hide y axis"),
+            AxisKey::HideZAxis => write!(f, "hide z axis"),
+            AxisKey::Width(value) => write!(f, "width={value}"),
+            AxisKey::Height(value) => write!(f, "height={value}"),
+            AxisKey::ScaleOnlyAxis => write!(f, "scale only axis"),
+            AxisKey::Aspect(value) => value.fmt_with(f, format),
+            AxisKey::EnlargeLimits(value) => write!(f, "enlargelimits={value}"),
+            AxisKey::ReverseXAxis => write!(f, "x dir=reverse"),
+            AxisKey::ReverseYAxis => write!(f, "y dir=reverse"),
+            AxisKey::ReverseZAxis => write!(f, "z dir=reverse"),
+        }
+    }
+}
+
+/// List of tick positions e.g. `{0,0.5,1}`.
+struct TickList<'a>(&'a [f64], NumberFormat);
+impl fmt::Display for TickList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return write!(f, "\\empty");
+        }
+        let ticks: Vec<String> = self
+            .0
+            .iter()
+            .map(|tick| self.1.display(*tick).to_string())
+            .collect();
+        write!(f, "{{{}}}", ticks.join(","))
+    }
+}
+
+/// List of tick labels, each enclosed in braces e.g. `{{a},{b, c}}`.
+struct LabelList<'a>(&'a [String]);
+impl fmt::Display for LabelList<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let labels: Vec<String> = self
+            .0
+            .iter()
+            .map(|label| {
+                if is_balanced(label) {
+                    format!("{{{label}}}")
+                } else {
+                    format!("{{{}}}", escape(label))
+                }
+            })
+            .collect();
+        write!(f, "{{{}}}", labels.join(","))
+    }
+}
+
+/// Whether the braces of `text` are balanced and it does not end in the
+/// middle of a control sequence. Escaped braces e.g. `\{` are ignored.
+fn is_balanced(text: &str) -> bool {
+    let mut depth = 0usize;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            // The character after a backslash is skipped.
+            '\\' if chars.next().is_none() => return false,
+            '{' => depth += 1,
+            '}' => match depth.checked_sub(1) {
+                Some(value) => depth = value,
+                None => return false,
+            },
+            _ => (),
+        }
+    }
+    depth == 0
+}
+
+/// Escape the backslashes and braces of `text`, so that they are written
+/// literally.
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '{' => escaped.push_str("\\{"),
+            '}' => escaped.push_str("\\}"),
+            _ => escaped.push(c),
         }
     }
+    escaped
 }
 
 /// Axis environment inside a [`Picture`].
@@ -69,27 +369,33 @@ impl fmt::Display for AxisKey {
 #[derive(Clone, Debug, Default)]
 pub struct Axis {
     keys: Vec<AxisKey>,
-    pub plots: Vec<Plot2D>,
+    pub plots: Vec<Plot>,
+    /// Order of the legend entries, as indices into [`Axis::plots`]. If
+    /// [`None`], the entries are in the same order as the plots. Otherwise,
+    /// plots that are not in the order do not appear in the legend.
+    ///
+    /// Only the legends of [`Plot2D`]s can be reordered, because their style
+    /// is fully defined by their keys.
+    pub legend_order: Option<Vec<usize>>,
 }
 
 impl fmt::Display for Axis {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\\begin{{axis}}")?;
-        // If there are keys, print one per line. It makes it easier for a
-        // human to find individual keys later.
-        if !self.keys.is_empty() {
-            writeln!(f, "[")?;
-            for key in self.keys.iter() {
-                writeln!(f, "\t{key},")?;
-            }
-            write!(f, "]")?;
-        }
-        writeln!(f)?;
-
-        for plot in self.plots.iter() {
-            writeln!(f, "{plot}")?;
-        }
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
 
+impl Axis {
+    /// Same as [`fmt::Display`], but `number_format` is used for the limits
+    /// and ticks, and for all the plots that do not have their own
+    /// [`Plot2D::number_format`].
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        self.fmt_begin(f, number_format)?;
+        self.fmt_plots(f, |f, _, plot| plot.fmt_with(f, number_format))?;
         write!(f, "\\end{{axis}}")?;
 
         Ok(())
@@ -149,6 +455,38 @@ impl Axis {
     pub fn set_y_label<S: Into<String>>(&mut self, label: S) {
         self.add_key(AxisKey::YLabel(label.into()));
     }
+    /// Set the label of the *z* axis. This can be valid LaTeX e.g. inline math.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_z_label("$z$~[m]");
+    /// ```
+    pub fn set_z_label<S: Into<String>>(&mut self, label: S) {
+        self.add_key(AxisKey::ZLabel(label.into()));
+    }
+    /// Set the width and height of the axis. These include the ticks and
+    /// labels, unless [`AxisKey::ScaleOnlyAxis`] is added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{
+    ///     axis::{Axis, AxisKey},
+    ///     length::{ColumnPreset, Length},
+    /// };
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.set_size(ColumnPreset::IeeeSingle, Length::inches(2.5));
+    /// axis.add_key(AxisKey::ScaleOnlyAxis);
+    /// ```
+    pub fn set_size<W: Into<Length>, H: Into<Length>>(&mut self, width: W, height: H) {
+        self.add_key(AxisKey::Width(width.into()));
+        self.add_key(AxisKey::Height(height.into()));
+    }
     /// Add a key to control the appearance of the axis. This will overwrite
     /// any previous mutually exclusive key.
     ///
@@ -175,6 +513,219 @@ impl Axis {
         }
         self.keys.push(key);
     }
+    /// Fill the area between the [`Plot2D`]s at indices `first` and `second`
+    /// of [`Axis::plots`]. Plots without a [`PlotKey::NamePath`] are named
+    /// `plot<index>`. The [`FillBetween`] is appended to the plots and returned
+    /// to customize its appearance.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `first` or `second` is out of bounds, or is not the index of a
+    /// [`Plot2D`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::{Plot2D, PlotKey}, Axis};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.plots.push(Plot2D::new().into());
+    /// axis.plots.push(Plot2D::new().into());
+    ///
+    /// let fill = axis.fill_between(0, 1);
+    /// fill.add_key(PlotKey::Custom(String::from("fill=gray")));
+    /// fill.soft_clip = Some((0.0, 1.0));
+    /// ```
+    pub fn fill_between(&mut self, first: usize, second: usize) -> &mut FillBetween {
+        let first = self.name_path(first);
+        let second = self.name_path(second);
+        self.plots.push(FillBetween::new(first, second).into());
+
+        match self.plots.last_mut() {
+            Some(Plot::FillBetween(fill)) => fill,
+            _ => unreachable!("a fill between was just pushed"),
+        }
+    }
+    /// Return the name of the path drawn by the [`Plot2D`] at `index`, naming
+    /// it if necessary.
+    fn name_path(&mut self, index: usize) -> String {
+        match &mut self.plots[index] {
+            Plot::Plot2D(plot) => plot.name_path_or(format!("plot{index}")),
+            _ => panic!("plot at index {index} is not a `Plot2D`"),
+        }
+    }
+    /// Stack the plots of the axis on top of each other. All plots have to be
+    /// [`Plot2D`]s with the same *x* values (or the same *y* values if stacked
+    /// along the *x* axis), in the same order. Stacked bars cannot have a
+    /// [`Type2D`] of their own. For stacked areas, every plot is also set to
+    /// [`Plot2D::closed_cycle`] to fill the area down to the previous plot;
+    /// this is undone when the plots are stacked again in another way.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::Plot2D, Axis, Stack};
+    ///
+    /// let mut axis = Axis::new();
+    /// for scale in [1.0, 2.0] {
+    ///     let mut plot = Plot2D::new();
+    ///     plot.coordinates = vec![(0.0, scale).into(), (1.0, 2.0 * scale).into()];
+    ///     axis.plots.push(plot.into());
+    /// }
+    /// axis.stack(Stack::AreaY).unwrap();
+    /// ```
+    pub fn stack(&mut self, stack: Stack) -> Result<(), StackError> {
+        let mut shared: Option<Vec<f64>> = None;
+        for (index, plot) in self.plots.iter().enumerate() {
+            let Plot::Plot2D(plot) = plot else {
+                return Err(StackError::NotPlot2D { index });
+            };
+            if stack.is_bar() && plot.type_2d().is_some() {
+                return Err(StackError::Type2D { index });
+            }
+            let values: Vec<f64> = plot
+                .coordinates
+                .iter()
+                .map(|c| if stack.is_along_y() { c.x } else { c.y })
+                .collect();
+            match &shared {
+                Some(shared) if *shared != values => {
+                    return Err(StackError::MismatchedCoordinates { index })
+                }
+                Some(_) => (),
+                None => shared = Some(values),
+            }
+        }
+
+        let was_area = self.keys.iter().any(|key| match key {
+            AxisKey::Stack(stack) => stack.is_area(),
+            _ => false,
+        });
+        if stack.is_area() || was_area {
+            for plot in self.plots.iter_mut() {
+                if let Plot::Plot2D(plot) = plot {
+                    plot.closed_cycle = stack.is_area();
+                }
+            }
+        }
+        self.add_key(AxisKey::Stack(stack));
+
+        Ok(())
+    }
+    /// Place the bars of all the [`Plot2D`]s with a [`Type2D::XBar`] or
+    /// [`Type2D::YBar`] type next to each other, centered on their
+    /// coordinates. Every bar gets the same `bar_width`, and its `bar_shift` is
+    /// computed from its position among the bar plots.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::{
+    ///     axis::{plot::{Plot2D, PlotKey, Type2D}, Axis},
+    ///     length::Length,
+    /// };
+    ///
+    /// let mut axis = Axis::new();
+    /// for _ in 0..3 {
+    ///     let mut plot = Plot2D::new();
+    ///     plot.add_key(PlotKey::Type2D(Type2D::YBar {
+    ///         bar_width: Length::pt(0.0),
+    ///         bar_shift: Length::pt(0.0),
+    ///     }));
+    ///     axis.plots.push(plot.into());
+    /// }
+    /// // The bars are shifted by -10pt, 0pt and 10pt.
+    /// axis.group_bars(Length::pt(10.0));
+    /// ```
+    pub fn group_bars(&mut self, bar_width: Length) {
+        let bars: Vec<(&mut Plot2D, Type2D)> = self
+            .plots
+            .iter_mut()
+            .filter_map(|plot| match plot {
+                Plot::Plot2D(plot) => match plot.type_2d() {
+                    Some(value @ (Type2D::XBar { .. } | Type2D::YBar { .. })) => {
+                        Some((plot, value))
+                    }
+                    _ => None,
+                },
+                _ => None,
+            })
+            .collect();
+
+        let center = (bars.len() as f64 - 1.0) / 2.0;
+        for (i, (plot, value)) in bars.into_iter().enumerate() {
+            let bar_shift = bar_width * (i as f64 - center);
+            let value = match value {
+                Type2D::XBar { .. } => Type2D::XBar {
+                    bar_width,
+                    bar_shift,
+                },
+                _ => Type2D::YBar {
+                    bar_width,
+                    bar_shift,
+                },
+            };
+            plot.add_key(PlotKey::Type2D(value));
+        }
+    }
+    /// Write the `\begin{axis}[AxisKeys]` line.
+    pub(crate) fn fmt_begin(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        write!(f, "\\begin{{axis}}")?;
+        // If there are keys, print one per line. It makes it easier for a
+        // human to find individual keys later.
+        if !self.keys.is_empty() {
+            writeln!(f, "[")?;
+            for key in self.keys.iter() {
+                write!(f, "\t")?;
+                key.fmt_with(f, number_format)?;
+                writeln!(f, ",")?;
+            }
+            write!(f, "]")?;
+        }
+        writeln!(f)
+    }
+    /// Write the plots with `fmt_plot`, which is given the index of each plot,
+    /// followed by their legend entries. If there is a
+    /// [`Axis::legend_order`], the legend entries are written before the plots
+    /// instead, each with an `\addlegendimage` that looks like its plot.
+    pub(crate) fn fmt_plots<F>(&self, f: &mut fmt::Formatter<'_>, mut fmt_plot: F) -> fmt::Result
+    where
+        F: FnMut(&mut fmt::Formatter<'_>, usize, &Plot) -> fmt::Result,
+    {
+        if let Some(order) = &self.legend_order {
+            for &index in order.iter() {
+                if let Some(Plot::Plot2D(plot)) = self.plots.get(index) {
+                    if let Some(legend) = plot.legend_entry() {
+                        writeln!(f, "\t\\addlegendimage{{{}}}", plot.legend_image())?;
+                        writeln!(f, "\t\\addlegendentry{{{legend}}}")?;
+                    }
+                }
+            }
+        }
+
+        for (index, plot) in self.plots.iter().enumerate() {
+            fmt_plot(f, index, plot)?;
+            writeln!(f)?;
+            if let (None, Some(legend)) = (&self.legend_order, plot.legend_entry()) {
+                writeln!(f, "\t\\addlegendentry{{{legend}}}")?;
+            }
+        }
+
+        Ok(())
+    }
+    /// Add the packages and libraries needed by the axis and its plots to the
+    /// `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        // The axis keys only need PGFPlots itself. Custom keys are opaque;
+        // users have to add whatever they need to the preamble themselves.
+        for plot in self.plots.iter() {
+            plot.add_requirements(preamble);
+        }
+    }
     /// Return a [`String`] with valid LaTeX code that generates a standalone
     /// PDF with the axis in a default picture environment.
     ///
@@ -194,6 +745,7 @@ impl Axis {
     /// assert_eq!(
     /// r#"\documentclass{standalone}
     /// \usepackage{pgfplots}
+    /// \pgfplotsset{compat=1.16}
     /// \begin{document}
     /// \begin{tikzpicture}
     /// \begin{axis}
@@ -203,9 +755,25 @@ impl Axis {
     /// axis.standalone_string());
     /// ```
     pub fn standalone_string(&self) -> String {
-        let mut picture = Picture::new();
-        picture.axes.push(self.clone());
-        picture.standalone_string()
+        Standalone::Axis(self).to_string()
+    }
+    /// Write the same LaTeX code returned by [`Axis::standalone_string`] into
+    /// `writer`. The code is streamed as it is generated, so no intermediate
+    /// [`String`] is built. You probably want to wrap unbuffered writers (e.g. a
+    /// [`std::fs::File`]) in a [`std::io::BufWriter`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::Axis;
+    /// use std::{fs::File, io::BufWriter};
+    ///
+    /// let mut axis = Axis::new();
+    /// let file = File::create("figure.tex").unwrap();
+    /// axis.write_standalone(BufWriter::new(file)).unwrap();
+    /// ```
+    pub fn write_standalone<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        Standalone::Axis(self).write(writer)
     }
     /// Show the axis in a default [`Picture`] as a standalone PDF. This will
     /// create a file in the location returned by [`std::env::temp_dir()`] and
@@ -221,9 +789,205 @@ impl Axis {
     /// ```
     #[cfg(feature = "inclusive")]
     pub fn show(&self) -> Result<(), ShowPdfError> {
-        let mut picture = Picture::new();
-        picture.axes.push(self.clone());
-        picture.show()
+        self.show_with(&Tectonic)
+    }
+    /// Same as [`Axis::show`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::Axis, compiler::Engine};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.show_with(&Engine::LuaLatex);
+    /// ```
+    pub fn show_with<C: Compiler + ?Sized>(&self, compiler: &C) -> Result<(), ShowPdfError> {
+        Standalone::Axis(self).show_with(compiler)
+    }
+    /// Compile the axis in a default [`Picture`] as a standalone PDF and
+    /// return its raw bytes. This does not create any file nor open a PDF
+    /// viewer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// let pdf_data = axis.to_pdf_bytes().unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn to_pdf_bytes(&self) -> Result<Vec<u8>, ShowPdfError> {
+        self.to_pdf_bytes_with(&Tectonic)
+    }
+    /// Same as [`Axis::to_pdf_bytes`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::Axis, compiler::Engine};
+    ///
+    /// let mut axis = Axis::new();
+    /// let pdf_data = axis.to_pdf_bytes_with(&Engine::PdfLatex).unwrap();
+    /// ```
+    pub fn to_pdf_bytes_with<C: Compiler + ?Sized>(
+        &self,
+        compiler: &C,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        Standalone::Axis(self).to_pdf_bytes_with(compiler)
+    }
+    /// Compile the axis in a default [`Picture`] as a standalone PDF and write
+    /// it to `path`. If the file already exists, it will be overwritten.
+    /// Unlike [`Axis::show`], this will not open a PDF viewer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.save_pdf("figure.pdf").unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn save_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), ShowPdfError> {
+        self.save_pdf_with(&Tectonic, path)
+    }
+    /// Same as [`Axis::save_pdf`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::Axis, compiler::Engine};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.save_pdf_with(&Engine::XeLatex, "figure.pdf").unwrap();
+    /// ```
+    pub fn save_pdf_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        &self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        Standalone::Axis(self).save_pdf_with(compiler, path)
+    }
+    /// Compile the axis in a default [`Picture`] as a standalone SVG image and
+    /// return its contents. The image is converted from the same PDF document
+    /// generated by [`Axis::to_pdf_bytes`], so both look exactly the same.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `dvisvgm` (included in most LaTeX
+    /// distributions) to be available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// let svg_data = axis.to_svg().unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn to_svg(&self) -> Result<String, ShowPdfError> {
+        self.to_svg_with(&Tectonic)
+    }
+    /// Same as [`Axis::to_svg`], but the PDF is compiled with the given
+    /// [`Compiler`] before converting it into an SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::Axis, compiler::Engine};
+    ///
+    /// let mut axis = Axis::new();
+    /// let svg_data = axis.to_svg_with(&Engine::PdfLatex).unwrap();
+    /// ```
+    pub fn to_svg_with<C: Compiler + ?Sized>(&self, compiler: &C) -> Result<String, ShowPdfError> {
+        Standalone::Axis(self).to_svg_with(compiler)
+    }
+    /// Compile the axis in a default [`Picture`] as a standalone SVG image and
+    /// write it to `path`. If the file already exists, it will be overwritten.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `dvisvgm` (included in most LaTeX
+    /// distributions) to be available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::Axis;
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.save_svg("figure.svg").unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn save_svg<P: AsRef<Path>>(&self, path: P) -> Result<(), ShowPdfError> {
+        self.save_svg_with(&Tectonic, path)
+    }
+    /// Same as [`Axis::save_svg`], but the PDF is compiled with the given
+    /// [`Compiler`] before converting it into an SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::Axis, compiler::Engine};
+    ///
+    /// let mut axis = Axis::new();
+    /// axis.save_svg_with(&Engine::XeLatex, "figure.svg").unwrap();
+    /// ```
+    pub fn save_svg_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        &self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        Standalone::Axis(self).save_svg_with(compiler, path)
+    }
+    /// Compile the axis in a default [`Picture`] as a standalone PNG image and
+    /// return its raw bytes. The image is rasterized from the same PDF document
+    /// generated by [`Axis::to_pdf_bytes`] with a resolution of `dpi` dots per
+    /// inch.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `pdftocairo` (included in Poppler) to be
+    /// available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::Axis, compiler::Background};
+    ///
+    /// let mut axis = Axis::new();
+    /// let png_data = axis.to_png(300, Background::White).unwrap();
+    /// ```
+    #[cfg(all(feature = "inclusive", feature = "png"))]
+    pub fn to_png(&self, dpi: u32, background: Background) -> Result<Vec<u8>, ShowPdfError> {
+        self.to_png_with(&Tectonic, dpi, background)
+    }
+    /// Same as [`Axis::to_png`], but the PDF is compiled with the given
+    /// [`Compiler`] before rasterizing it into a PNG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::Axis, compiler::{Background, Engine}};
+    ///
+    /// let mut axis = Axis::new();
+    /// let png_data = axis
+    ///     .to_png_with(&Engine::PdfLatex, 150, Background::Transparent)
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "png")]
+    pub fn to_png_with<C: Compiler + ?Sized>(
+        &self,
+        compiler: &C,
+        dpi: u32,
+        background: Background,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        Standalone::Axis(self).to_png_with(compiler, dpi, background)
     }
 }
 
@@ -244,5 +1008,502 @@ impl fmt::Display for Scale {
     }
 }
 
+/// Colormaps available in PGFPlots.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Colormap {
+    /// Custom colormap key written verbatim e.g. `colormap name=mymap`, or a
+    /// colormap definition like `colormap={mymap}{rgb=(1,0,0) rgb=(0,0,1)}`.
+    Custom(String),
+    Hot,
+    Hot2,
+    Jet,
+    Blackwhite,
+    Bluered,
+    Cool,
+    Greenyellow,
+    Redyellow,
+    Violet,
+    Viridis,
+}
+impl fmt::Display for Colormap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Colormap::Custom(key) => write!(f, "{key}"),
+            Colormap::Hot => write!(f, "colormap/hot"),
+            Colormap::Hot2 => write!(f, "colormap/hot2"),
+            Colormap::Jet => write!(f, "colormap/jet"),
+            Colormap::Blackwhite => write!(f, "colormap/blackwhite"),
+            Colormap::Bluered => write!(f, "colormap/bluered"),
+            Colormap::Cool => write!(f, "colormap/cool"),
+            Colormap::Greenyellow => write!(f, "colormap/greenyellow"),
+            Colormap::Redyellow => write!(f, "colormap/redyellow"),
+            Colormap::Violet => write!(f, "colormap/violet"),
+            Colormap::Viridis => write!(f, "colormap/viridis"),
+        }
+    }
+}
+
+/// Control the position of the legend inside or outside of an [`Axis`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum LegendPos {
+    /// Inside the axis, in the bottom left corner.
+    SouthWest,
+    /// Inside the axis, in the bottom right corner.
+    SouthEast,
+    /// Inside the axis, in the top left corner.
+    NorthWest,
+    /// Inside the axis, in the top right corner. This is the default.
+    NorthEast,
+    /// Outside the axis, next to its top right corner.
+    OuterNorthEast,
+    /// Outside the axis, next to the middle of its right side.
+    OuterEast,
+    /// Outside the axis, next to its bottom right corner.
+    OuterSouthEast,
+}
+impl fmt::Display for LegendPos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LegendPos::SouthWest => write!(f, "legend pos=south west"),
+            LegendPos::SouthEast => write!(f, "legend pos=south east"),
+            LegendPos::NorthWest => write!(f, "legend pos=north west"),
+            LegendPos::NorthEast => write!(f, "legend pos=north east"),
+            LegendPos::OuterNorthEast => write!(f, "legend pos=outer north east"),
+            // PGFPlots has no `legend pos` for these, so they are placed with
+            // the same offset as `outer north east`.
+            LegendPos::OuterEast => write!(f, "legend style={{at={{(1.03,0.5)}}, anchor=west}}"),
+            LegendPos::OuterSouthEast => {
+                write!(f, "legend style={{at={{(1.03,0)}}, anchor=south west}}")
+            }
+        }
+    }
+}
+
+/// Control how the numbers of tick labels are written.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{AxisKey, TickLabelFormat, TickNotation};
+///
+/// let format = TickLabelFormat {
+///     notation: TickNotation::Fixed,
+///     precision: Some(1),
+/// };
+/// assert_eq!(
+///     AxisKey::YTickLabelFormat(format).to_string(),
+///     "yticklabel style={/pgf/number format/fixed, /pgf/number format/precision=1}"
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct TickLabelFormat {
+    /// Notation of the numbers. Defaults to [`TickNotation::Auto`].
+    pub notation: TickNotation,
+    /// Number of digits after the decimal point. If [`None`], PGF rounds to
+    /// two digits.
+    pub precision: Option<usize>,
+}
+impl fmt::Display for TickLabelFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut options = Vec::new();
+        match self.notation {
+            TickNotation::Auto => (),
+            TickNotation::Fixed => options.push(String::from("fixed")),
+            TickNotation::FixedZerofill => options.push(String::from("fixed zerofill")),
+            TickNotation::Scientific => options.push(String::from("sci")),
+        }
+        if let Some(precision) = self.precision {
+            options.push(format!("precision={precision}"));
+        }
+        let options: Vec<String> = options
+            .iter()
+            .map(|option| format!("/pgf/number format/{option}"))
+            .collect();
+        write!(f, "{}", options.join(", "))
+    }
+}
+
+/// Notation of the numbers in tick labels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TickNotation {
+    /// Let PGF choose between fixed point and scientific notation.
+    #[default]
+    Auto,
+    /// Fixed point notation e.g. `1234.5`.
+    Fixed,
+    /// Fixed point notation with trailing zeros up to the precision e.g.
+    /// `1.50`.
+    FixedZerofill,
+    /// Scientific notation e.g. `1.23 \cdot 10^{3}`.
+    Scientific,
+}
+
+/// Control how the plots of an [`Axis`] are stacked.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Stack {
+    /// Stack vertical bars on top of each other. The plots should not have a
+    /// [`Type2D`] of their own.
+    YBar,
+    /// Stack horizontal bars next to each other. The plots should not have a
+    /// [`Type2D`] of their own.
+    XBar,
+    /// Add the *y* values of each plot to those of the previous plots.
+    Y,
+    /// Add the *x* values of each plot to those of the previous plots.
+    X,
+    /// Same as [`Stack::Y`], but the area between each plot and the previous
+    /// one is filled.
+    AreaY,
+    /// Same as [`Stack::X`], but the area between each plot and the previous
+    /// one is filled.
+    AreaX,
+}
+impl fmt::Display for Stack {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stack::YBar => write!(f, "ybar stacked"),
+            Stack::XBar => write!(f, "xbar stacked"),
+            Stack::Y => write!(f, "stack plots=y"),
+            Stack::X => write!(f, "stack plots=x"),
+            Stack::AreaY => write!(f, "stack plots=y, area style"),
+            Stack::AreaX => write!(f, "stack plots=x, area style"),
+        }
+    }
+}
+impl Stack {
+    /// Whether the *y* values are stacked i.e. the plots have to share their
+    /// *x* values.
+    fn is_along_y(&self) -> bool {
+        match self {
+            Stack::YBar | Stack::Y | Stack::AreaY => true,
+            Stack::XBar | Stack::X | Stack::AreaX => false,
+        }
+    }
+    /// Whether bars are stacked.
+    fn is_bar(&self) -> bool {
+        matches!(self, Stack::YBar | Stack::XBar)
+    }
+    /// Whether the areas between the plots are filled.
+    fn is_area(&self) -> bool {
+        matches!(self, Stack::AreaY | Stack::AreaX)
+    }
+}
+
+/// The error type returned when the plots of an [`Axis`] cannot be stacked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StackError {
+    /// The plot at `index` is not a [`Plot2D`].
+    NotPlot2D { index: usize },
+    /// The plot at `index` does not have the same *x* (or *y*) values as the
+    /// first plot.
+    MismatchedCoordinates { index: usize },
+    /// The plot at `index` has its own [`Type2D`], which would overwrite the
+    /// stacked bars.
+    Type2D { index: usize },
+}
+impl fmt::Display for StackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StackError::NotPlot2D { index } => {
+                write!(f, "plot {index} is not a two-dimensional plot")
+            }
+            StackError::MismatchedCoordinates { index } => {
+                write!(
+                    f,
+                    "plot {index} has different coordinates than the first plot"
+                )
+            }
+            StackError::Type2D { index } => {
+                write!(f, "plot {index} has its own type of plot")
+            }
+        }
+    }
+}
+impl std::error::Error for StackError {}
+
+/// Control which grid lines are drawn in an [`Axis`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Grid {
+    /// Grid lines at the major ticks.
+    Major,
+    /// Grid lines at the minor ticks.
+    Minor,
+    /// Grid lines at both the major and minor ticks.
+    Both,
+    /// No grid lines. This is the default.
+    None,
+}
+impl fmt::Display for Grid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Grid::Major => write!(f, "major"),
+            Grid::Minor => write!(f, "minor"),
+            Grid::Both => write!(f, "both"),
+            Grid::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Control where the axis lines of an [`Axis`] are drawn.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum AxisLines {
+    /// Frame around the axis. This is the default.
+    Box,
+    /// Lines along the left and bottom sides of the axis.
+    Left,
+    /// Lines through the origin with arrows, if the origin is inside the
+    /// axis. Otherwise, the lines are at the closest side.
+    Middle,
+    /// Same as [`AxisLines::Middle`].
+    Center,
+    /// Lines along the right and top sides of the axis.
+    Right,
+    /// No axis lines. Unlike [`AxisKey::HideAxis`], the ticks and labels are
+    /// still drawn.
+    None,
+}
+impl fmt::Display for AxisLines {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AxisLines::Box => write!(f, "box"),
+            AxisLines::Left => write!(f, "left"),
+            AxisLines::Middle => write!(f, "middle"),
+            AxisLines::Center => write!(f, "center"),
+            AxisLines::Right => write!(f, "right"),
+            AxisLines::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Control where the line of the *x* axis of an [`Axis`] is drawn.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum XAxisLine {
+    /// Lines along both the bottom and top sides of the axis. This is the
+    /// default.
+    Box,
+    /// Line along the top side of the axis.
+    Top,
+    /// Line at *y = 0*, if it is inside the axis.
+    Middle,
+    /// Line along the bottom side of the axis.
+    Bottom,
+    /// No line.
+    None,
+}
+impl fmt::Display for XAxisLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            XAxisLine::Box => write!(f, "box"),
+            XAxisLine::Top => write!(f, "top"),
+            XAxisLine::Middle => write!(f, "middle"),
+            XAxisLine::Bottom => write!(f, "bottom"),
+            XAxisLine::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Control where the line of the *y* axis of an [`Axis`] is drawn.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum YAxisLine {
+    /// Lines along both the left and right sides of the axis. This is the
+    /// default.
+    Box,
+    /// Line along the left side of the axis.
+    Left,
+    /// Line at *x = 0*, if it is inside the axis.
+    Middle,
+    /// Line along the right side of the axis.
+    Right,
+    /// No line.
+    None,
+}
+impl fmt::Display for YAxisLine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            YAxisLine::Box => write!(f, "box"),
+            YAxisLine::Left => write!(f, "left"),
+            YAxisLine::Middle => write!(f, "middle"),
+            YAxisLine::Right => write!(f, "right"),
+            YAxisLine::None => write!(f, "none"),
+        }
+    }
+}
+
+/// Style of a line e.g. a grid or axis line. Only the properties that are set
+/// are written; the others keep their default value.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::{
+///     axis::{AxisKey, LinePattern, LineStyle, LineThickness},
+///     color::NamedColor,
+/// };
+///
+/// let style = LineStyle {
+///     pattern: Some(LinePattern::Dotted),
+///     thickness: Some(LineThickness::VeryThin),
+///     color: Some(NamedColor::Gray.into()),
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     AxisKey::GridStyle(style).to_string(),
+///     "grid style={dotted, very thin, color=gray}"
+/// );
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct LineStyle {
+    /// Dash pattern of the line.
+    pub pattern: Option<LinePattern>,
+    /// Thickness of the line.
+    pub thickness: Option<LineThickness>,
+    /// Color of the line.
+    pub color: Option<Color>,
+    /// Opacity of the line, between `0.0` (transparent) and `1.0` (opaque).
+    pub opacity: Option<f64>,
+}
+impl fmt::Display for LineStyle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut options = Vec::new();
+        if let Some(pattern) = self.pattern {
+            options.push(pattern.to_string());
+        }
+        if let Some(thickness) = self.thickness {
+            options.push(thickness.to_string());
+        }
+        if let Some(color) = &self.color {
+            options.push(format!("color={color}"));
+        }
+        if let Some(opacity) = self.opacity {
+            options.push(format!("opacity={opacity}"));
+        }
+        write!(f, "{}", options.join(", "))
+    }
+}
+
+/// Dash pattern of a [`LineStyle`].
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum LinePattern {
+    Solid,
+    Dotted,
+    DenselyDotted,
+    LooselyDotted,
+    Dashed,
+    DenselyDashed,
+    LooselyDashed,
+    DashDotted,
+    DenselyDashDotted,
+    LooselyDashDotted,
+}
+impl fmt::Display for LinePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LinePattern::Solid => write!(f, "solid"),
+            LinePattern::Dotted => write!(f, "dotted"),
+            LinePattern::DenselyDotted => write!(f, "densely dotted"),
+            LinePattern::LooselyDotted => write!(f, "loosely dotted"),
+            LinePattern::Dashed => write!(f, "dashed"),
+            LinePattern::DenselyDashed => write!(f, "densely dashed"),
+            LinePattern::LooselyDashed => write!(f, "loosely dashed"),
+            LinePattern::DashDotted => write!(f, "dashdotted"),
+            LinePattern::DenselyDashDotted => write!(f, "densely dashdotted"),
+            LinePattern::LooselyDashDotted => write!(f, "loosely dashdotted"),
+        }
+    }
+}
+
+/// Thickness of a [`LineStyle`], from thinnest to thickest.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum LineThickness {
+    UltraThin,
+    VeryThin,
+    Thin,
+    Semithick,
+    Thick,
+    VeryThick,
+    UltraThick,
+}
+impl fmt::Display for LineThickness {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineThickness::UltraThin => write!(f, "ultra thin"),
+            LineThickness::VeryThin => write!(f, "very thin"),
+            LineThickness::Thin => write!(f, "thin"),
+            LineThickness::Semithick => write!(f, "semithick"),
+            LineThickness::Thick => write!(f, "thick"),
+            LineThickness::VeryThick => write!(f, "very thick"),
+            LineThickness::UltraThick => write!(f, "ultra thick"),
+        }
+    }
+}
+
+/// Control the relative scaling of the axes of an [`Axis`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Aspect {
+    /// One unit has the same length along every axis. The limits of the axes
+    /// are enlarged to keep the size of the axis.
+    Equal,
+    /// One unit has the same length along every axis. The axis is shrunk to
+    /// fit its limits, which is useful for images.
+    EqualImage,
+    /// Ratio between the lengths of one unit along the *x*, *y* (and *z*)
+    /// axes e.g. `vec![1.0, 2.0]` makes one unit along the *y* axis twice as
+    /// long as along the *x* axis.
+    UnitVectorRatio(Vec<f64>),
+}
+impl fmt::Display for Aspect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl Aspect {
+    /// Same as [`fmt::Display`], but with a custom [`NumberFormat`].
+    pub(crate) fn fmt_with(&self, f: &mut fmt::Formatter<'_>, format: NumberFormat) -> fmt::Result {
+        match self {
+            Aspect::Equal => write!(f, "axis equal"),
+            Aspect::EqualImage => write!(f, "axis equal image"),
+            Aspect::UnitVectorRatio(ratio) => {
+                let ratio: Vec<String> = ratio
+                    .iter()
+                    .map(|value| format.display(*value).to_string())
+                    .collect();
+                write!(f, "unit vector ratio={{{}}}", ratio.join(" "))
+            }
+        }
+    }
+}
+
+/// Control the position of the colorbar.
+#[derive(Clone, Copy, Debug)]
+pub enum Colorbar {
+    /// Vertical colorbar on the right of the axis.
+    Right,
+    /// Vertical colorbar on the left of the axis.
+    Left,
+    /// Horizontal colorbar below the axis.
+    Horizontal,
+}
+impl fmt::Display for Colorbar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Colorbar::Right => write!(f, "colorbar"),
+            Colorbar::Left => write!(f, "colorbar left"),
+            Colorbar::Horizontal => write!(f, "colorbar horizontal"),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -1,16 +1,41 @@
-use crate::axis::{plot::coordinate::Coordinate2D, Axis};
-use std::fmt;
+use crate::axis::plot::{
+    boxplot::BoxPlot,
+    contour::ContourPlot,
+    coordinate::{Coordinate2D, Coordinate3D, PointMeta},
+    error_band::ErrorBand,
+    fill_between::FillBetween,
+    matrix::MatrixPlot,
+};
+use crate::{
+    color::Color, compiler::Compiler, length::Length, number::NumberFormat, preamble::Preamble,
+    ShowPdfError, Standalone,
+};
+use std::{fmt, io, path::Path};
 
+#[cfg(feature = "png")]
+use crate::compiler::Background;
 #[cfg(feature = "inclusive")]
-use crate::ShowPdfError;
+use crate::compiler::Tectonic;
 
 // Only imported for documentation. If you notice that this is no longer the
 // case, please change it.
 #[allow(unused_imports)]
-use crate::Picture;
+use crate::{axis::Axis, length::Unit, Picture};
 
+/// Box plots of one-dimensional samples.
+pub mod boxplot;
+/// Contour plots of two-dimensional scalar fields.
+pub mod contour;
 /// Coordinates inside a plot.
 pub mod coordinate;
+/// Lines with a shaded band around them.
+pub mod error_band;
+/// Filled areas between two plots.
+pub mod fill_between;
+/// Histograms of one-dimensional samples.
+pub mod histogram;
+/// Matrix plots (heatmaps) of two-dimensional arrays.
+pub mod matrix;
 
 /// PGFPlots options passed to a plot.
 ///
@@ -41,6 +66,35 @@ pub enum PlotKey {
     /// Note that error bars won't be drawn unless [`PlotKey::YError`] is also
     /// set.
     YErrorDirection(ErrorDirection),
+    /// Control the type of three dimensional plots.
+    Type3D(Type3D),
+    /// Number of rows (scanlines) of the mesh of a three dimensional plot.
+    MeshRows(usize),
+    /// Number of columns i.e. coordinates per scanline of the mesh of a three
+    /// dimensional plot.
+    MeshCols(usize),
+    /// Control the source of the point meta data of each coordinate e.g. to
+    /// map it into a color.
+    PointMeta(PointMetaSource),
+    /// Draw a scatter plot i.e. color each marker according to its point meta
+    /// data.
+    Scatter,
+    /// Control the source of the point meta data used by
+    /// [`PlotKey::Scatter`]. This is the same as [`PlotKey::PointMeta`].
+    ScatterSrc(PointMetaSource),
+    /// Name the path drawn by the plot e.g. to fill the area between two plots
+    /// with a [`FillBetween`]. Names cannot contain the word `and` surrounded
+    /// by spaces.
+    NamePath(String),
+    /// Exclude the plot from the legend and from the cycle list i.e. the next
+    /// plot gets the same cycle list style.
+    ForgetPlot,
+    /// Color of the lines and markers of the plot.
+    Color(Color),
+    /// Fill the area enclosed by the plot with a color.
+    Fill(Color),
+    /// Width of the lines of the plot.
+    LineWidth(Length),
 }
 
 impl fmt::Display for PlotKey {
@@ -52,6 +106,187 @@ impl fmt::Display for PlotKey {
             PlotKey::XErrorDirection(value) => write!(f, "error bars/x dir={value}"),
             PlotKey::YError(value) => write!(f, "error bars/y {value}"),
             PlotKey::YErrorDirection(value) => write!(f, "error bars/y dir={value}"),
+            PlotKey::Type3D(value) => write!(f, "{value}"),
+            PlotKey::MeshRows(value) => write!(f, "mesh/rows={value}"),
+            PlotKey::MeshCols(value) => write!(f, "mesh/cols={value}"),
+            PlotKey::PointMeta(value) => write!(f, "point meta={value}"),
+            PlotKey::Scatter => write!(f, "scatter"),
+            PlotKey::ScatterSrc(value) => write!(f, "scatter src={value}"),
+            PlotKey::NamePath(value) => write!(f, "name path={value}"),
+            PlotKey::ForgetPlot => write!(f, "forget plot"),
+            PlotKey::Color(value) => write!(f, "color={value}"),
+            PlotKey::Fill(value) => write!(f, "fill={value}"),
+            PlotKey::LineWidth(value) => write!(f, "line width={value}"),
+        }
+    }
+}
+
+impl PlotKey {
+    /// Add the packages and libraries needed by the key to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        // Custom keys are opaque. Users have to add whatever they need to the
+        // preamble themselves. The other keys only need PGFPlots itself.
+        if let PlotKey::Type2D(value) = self {
+            value.add_requirements(preamble);
+        }
+    }
+}
+
+/// Any plot that can be added to an [`Axis`].
+///
+/// Two and three-dimensional plots can be mixed inside the same [`Axis`]. All
+/// plot types can be converted into a [`Plot`] with [`Into::into`].
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{plot::{Plot2D, Plot3D}, Axis};
+///
+/// let mut axis = Axis::new();
+/// axis.plots.push(Plot2D::new().into());
+/// axis.plots.push(Plot3D::new().into());
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Plot {
+    Plot2D(Plot2D),
+    Plot3D(Plot3D),
+    ContourPlot(ContourPlot),
+    MatrixPlot(MatrixPlot),
+    BoxPlot(BoxPlot),
+    FillBetween(FillBetween),
+    ErrorBand(ErrorBand),
+}
+
+impl fmt::Display for Plot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl From<Plot2D> for Plot {
+    fn from(plot: Plot2D) -> Self {
+        Plot::Plot2D(plot)
+    }
+}
+
+impl From<Plot3D> for Plot {
+    fn from(plot: Plot3D) -> Self {
+        Plot::Plot3D(plot)
+    }
+}
+
+impl From<ContourPlot> for Plot {
+    fn from(plot: ContourPlot) -> Self {
+        Plot::ContourPlot(plot)
+    }
+}
+
+impl From<MatrixPlot> for Plot {
+    fn from(plot: MatrixPlot) -> Self {
+        Plot::MatrixPlot(plot)
+    }
+}
+
+impl From<BoxPlot> for Plot {
+    fn from(plot: BoxPlot) -> Self {
+        Plot::BoxPlot(plot)
+    }
+}
+
+impl From<FillBetween> for Plot {
+    fn from(plot: FillBetween) -> Self {
+        Plot::FillBetween(plot)
+    }
+}
+
+impl From<ErrorBand> for Plot {
+    fn from(plot: ErrorBand) -> Self {
+        Plot::ErrorBand(plot)
+    }
+}
+
+impl Plot {
+    /// Same as [`fmt::Display`], but with a fallback [`NumberFormat`] for the
+    /// coordinates.
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        match self {
+            Plot::Plot2D(plot) => plot.fmt_with(f, number_format),
+            Plot::Plot3D(plot) => plot.fmt_with(f, number_format),
+            Plot::ContourPlot(plot) => plot.fmt_with(f, number_format),
+            Plot::MatrixPlot(plot) => plot.fmt_with(f, number_format),
+            Plot::BoxPlot(plot) => plot.fmt_with(f, number_format),
+            Plot::FillBetween(plot) => plot.fmt_with(f, number_format),
+            Plot::ErrorBand(plot) => plot.fmt_with(f, number_format),
+        }
+    }
+    /// Write the plot such that the coordinates are read from the data file at
+    /// `path` (as written by [`Plot::write_table`]) instead of being inlined.
+    pub(crate) fn fmt_table(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        path: &str,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        match self {
+            Plot::Plot2D(plot) => plot.fmt_table(f, path),
+            Plot::Plot3D(plot) => plot.fmt_table(f, path),
+            Plot::ContourPlot(plot) => plot.fmt_table(f, path, number_format),
+            Plot::MatrixPlot(plot) => plot.fmt_table(f, path),
+            Plot::BoxPlot(plot) => plot.fmt_table(f, path, number_format),
+            // There are no coordinates to read from a data file.
+            Plot::FillBetween(plot) => plot.fmt_with(f, number_format),
+            Plot::ErrorBand(plot) => plot.fmt_table(f, path),
+        }
+    }
+    /// Whether the plot has coordinates to be written to a data file with
+    /// [`Plot::write_table`].
+    pub(crate) fn has_table(&self) -> bool {
+        !matches!(self, Plot::FillBetween(_))
+    }
+    /// Write the coordinates as a whitespace separated table with a header
+    /// row.
+    pub(crate) fn write_table<W: io::Write>(
+        &self,
+        writer: W,
+        number_format: NumberFormat,
+    ) -> io::Result<()> {
+        match self {
+            Plot::Plot2D(plot) => plot.write_table(writer, number_format),
+            Plot::Plot3D(plot) => plot.write_table(writer, number_format),
+            Plot::ContourPlot(plot) => plot.write_table(writer, number_format),
+            Plot::MatrixPlot(plot) => plot.write_table(writer, number_format),
+            Plot::BoxPlot(plot) => plot.write_table(writer, number_format),
+            Plot::FillBetween(_) => Ok(()),
+            Plot::ErrorBand(plot) => plot.write_table(writer, number_format),
+        }
+    }
+    /// Return the entry of the plot in the legend, if any.
+    pub(crate) fn legend_entry(&self) -> Option<&str> {
+        match self {
+            Plot::Plot2D(plot) => plot.legend_entry(),
+            Plot::ErrorBand(plot) => plot.legend.as_deref(),
+            Plot::Plot3D(_)
+            | Plot::ContourPlot(_)
+            | Plot::MatrixPlot(_)
+            | Plot::BoxPlot(_)
+            | Plot::FillBetween(_) => None,
+        }
+    }
+    /// Add the packages and libraries needed by the plot to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        match self {
+            Plot::Plot2D(plot) => plot.add_requirements(preamble),
+            Plot::Plot3D(plot) => plot.add_requirements(preamble),
+            Plot::ContourPlot(plot) => plot.add_requirements(preamble),
+            Plot::MatrixPlot(plot) => plot.add_requirements(preamble),
+            Plot::BoxPlot(plot) => plot.add_requirements(preamble),
+            Plot::FillBetween(plot) => plot.add_requirements(preamble),
+            Plot::ErrorBand(plot) => plot.add_requirements(preamble),
         }
     }
 }
@@ -83,29 +318,49 @@ impl fmt::Display for PlotKey {
 pub struct Plot2D {
     keys: Vec<PlotKey>,
     pub coordinates: Vec<Coordinate2D>,
+    /// Format of the numbers in the coordinates. If [`None`], the
+    /// [`Picture::number_format`] is used (or the default format if the plot is
+    /// not inside a [`Picture`]).
+    pub number_format: Option<NumberFormat>,
+    /// Whether the path is closed with `\closedcycle` i.e. down to the *x*
+    /// axis, or down to the previous plot if the plots of the [`Axis`] are
+    /// stacked (see [`Axis::stack`]). This is needed to fill the area under the
+    /// plot. Defaults to `false`.
+    pub closed_cycle: bool,
+    /// Entry of the plot in the legend of the [`Axis`], written with
+    /// `\addlegendentry` right after the plot. Braces in the entry have to be
+    /// balanced. The entry is not written if the plot has a
+    /// [`PlotKey::ForgetPlot`].
+    pub legend: Option<String>,
 }
 
 impl fmt::Display for Plot2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\t\\addplot[")?;
-        // If there are keys, print them one per line. It makes it easier for a
-        // human to find individual keys later.
-        if !self.keys.is_empty() {
-            writeln!(f)?;
-            for key in self.keys.iter() {
-                writeln!(f, "\t\t{key},")?;
-            }
-            write!(f, "\t")?;
-        }
-        writeln!(f, "] coordinates {{")?;
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl Plot2D {
+    /// Same as [`fmt::Display`], but `number_format` is used for the
+    /// coordinates unless the plot has its own [`Plot2D::number_format`].
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        let number_format = self.number_format.unwrap_or(number_format);
+
+        fmt_addplot(f, "addplot", &self.keys)?;
+        writeln!(f, " coordinates {{")?;
 
         for coordinate in self.coordinates.iter() {
-            writeln!(f, "\t\t{coordinate}")?;
+            write!(f, "\t\t")?;
+            coordinate.fmt_with(f, number_format)?;
+            writeln!(f)?;
         }
 
-        write!(f, "\t}};")?;
-
-        Ok(())
+        write!(f, "\t}}")?;
+        self.fmt_end(f)
     }
 }
 
@@ -134,19 +389,167 @@ impl Plot2D {
     /// plot.add_key(PlotKey::Type2D(SharpPlot));
     /// ```
     pub fn add_key(&mut self, key: PlotKey) {
-        match key {
-            PlotKey::Custom(_) => (),
-            _ => {
-                if let Some(index) = self
-                    .keys
-                    .iter()
-                    .position(|k| std::mem::discriminant(k) == std::mem::discriminant(&key))
-                {
-                    self.keys.remove(index);
+        add_key(&mut self.keys, key);
+    }
+    /// Return the name of the path drawn by the plot. If the plot has no
+    /// [`PlotKey::NamePath`], it is named `name` first.
+    pub(crate) fn name_path_or(&mut self, name: String) -> String {
+        let existing = self.keys.iter().find_map(|key| match key {
+            PlotKey::NamePath(name) => Some(name.clone()),
+            _ => None,
+        });
+        existing.unwrap_or_else(|| {
+            self.add_key(PlotKey::NamePath(name.clone()));
+            name
+        })
+    }
+    /// Return the entry of the plot in the legend, unless the plot is
+    /// forgotten.
+    pub(crate) fn legend_entry(&self) -> Option<&str> {
+        if self
+            .keys
+            .iter()
+            .any(|key| matches!(key, PlotKey::ForgetPlot))
+        {
+            return None;
+        }
+        self.legend.as_deref()
+    }
+    /// Return the options of an `\addlegendimage` command that looks like the
+    /// plot i.e. only the keys that control its style.
+    pub(crate) fn legend_image(&self) -> String {
+        let keys: Vec<String> = self
+            .keys
+            .iter()
+            .filter_map(|key| match key {
+                PlotKey::Custom(_)
+                | PlotKey::Color(_)
+                | PlotKey::Fill(_)
+                | PlotKey::LineWidth(_) => Some(key.to_string()),
+                // The bar width and shift would move the legend image.
+                PlotKey::Type2D(Type2D::XBar { .. }) => Some(String::from("xbar")),
+                PlotKey::Type2D(Type2D::YBar { .. }) => Some(String::from("ybar")),
+                PlotKey::Type2D(_) => Some(key.to_string()),
+                PlotKey::XError(_)
+                | PlotKey::XErrorDirection(_)
+                | PlotKey::YError(_)
+                | PlotKey::YErrorDirection(_)
+                | PlotKey::Type3D(_)
+                | PlotKey::MeshRows(_)
+                | PlotKey::MeshCols(_)
+                | PlotKey::PointMeta(_)
+                | PlotKey::Scatter
+                | PlotKey::ScatterSrc(_)
+                | PlotKey::NamePath(_)
+                | PlotKey::ForgetPlot => None,
+            })
+            .collect();
+        keys.join(", ")
+    }
+    /// Return the type of the plot, if set with [`PlotKey::Type2D`].
+    pub(crate) fn type_2d(&self) -> Option<Type2D> {
+        self.keys.iter().find_map(|key| match key {
+            PlotKey::Type2D(value) => Some(*value),
+            _ => None,
+        })
+    }
+    /// Whether any of the coordinates has an error in any direction.
+    fn has_errors(&self) -> bool {
+        self.coordinates.iter().any(Coordinate2D::has_errors)
+    }
+    /// Whether any of the coordinates has an error only in the positive or
+    /// negative direction.
+    fn has_asymmetric_errors(&self) -> bool {
+        self.coordinates
+            .iter()
+            .any(Coordinate2D::has_asymmetric_errors)
+    }
+    /// Whether any of the coordinates has point meta data.
+    fn has_point_meta(&self) -> bool {
+        self.coordinates.iter().any(|c| c.point_meta.is_some())
+    }
+    /// Write the plot such that the coordinates are read from the data file at
+    /// `path` (as written by [`Plot2D::write_table`]) instead of being inlined.
+    pub(crate) fn fmt_table(&self, f: &mut fmt::Formatter<'_>, path: &str) -> fmt::Result {
+        fmt_addplot(f, "addplot", &self.keys)?;
+        write!(f, " table")?;
+        let mut options = Vec::new();
+        if self.has_asymmetric_errors() {
+            options
+                .push("x error plus=exp, x error minus=exm, y error plus=eyp, y error minus=eym");
+        } else if self.has_errors() {
+            options.push("x error=ex, y error=ey");
+        }
+        if self.has_point_meta() {
+            options.push("meta=meta");
+        }
+        if !options.is_empty() {
+            write!(f, "[{}]", options.join(", "))?;
+        }
+        write!(f, " {{{path}}}")?;
+        self.fmt_end(f)
+    }
+    /// Write the end of the `\addplot` command after the coordinates.
+    fn fmt_end(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.closed_cycle {
+            write!(f, " \\closedcycle")?;
+        }
+        write!(f, ";")
+    }
+    /// Write the coordinates as a whitespace separated table with a header
+    /// row. Errors and point meta data are written only if any coordinate has
+    /// them. If any error is asymmetric, the errors in the positive and
+    /// negative directions are written separately. Missing errors are written
+    /// as zero, and missing point meta data as `nan`.
+    pub(crate) fn write_table<W: io::Write>(
+        &self,
+        mut writer: W,
+        number_format: NumberFormat,
+    ) -> io::Result<()> {
+        let number_format = self.number_format.unwrap_or(number_format);
+        let asymmetric_errors = self.has_asymmetric_errors();
+        let errors = self.has_errors() && !asymmetric_errors;
+        let point_meta = self.has_point_meta();
+        write!(writer, "x y")?;
+        if asymmetric_errors {
+            write!(writer, " exp exm eyp eym")?;
+        } else if errors {
+            write!(writer, " ex ey")?;
+        }
+        if point_meta {
+            write!(writer, " meta")?;
+        }
+        writeln!(writer)?;
+
+        for coordinate in self.coordinates.iter() {
+            let x = number_format.display(coordinate.x);
+            let y = number_format.display(coordinate.y);
+            write!(writer, "{x} {y}")?;
+            if errors {
+                let error_x = number_format.display(coordinate.error_x.unwrap_or(0.0));
+                let error_y = number_format.display(coordinate.error_y.unwrap_or(0.0));
+                write!(writer, " {error_x} {error_y}")?;
+            }
+            if asymmetric_errors {
+                let (error_x_plus, error_y_plus) = coordinate.errors_plus();
+                let (error_x_minus, error_y_minus) = coordinate.errors_minus();
+                for error in [error_x_plus, error_x_minus, error_y_plus, error_y_minus] {
+                    write!(writer, " {}", number_format.display(error))?;
                 }
             }
+            if point_meta {
+                write_point_meta(&mut writer, &coordinate.point_meta, number_format)?;
+            }
+            writeln!(writer)?;
+        }
+
+        writer.flush()
+    }
+    /// Add the packages and libraries needed by the plot to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        for key in self.keys.iter() {
+            key.add_requirements(preamble);
         }
-        self.keys.push(key);
     }
     /// Return a [`String`] with valid LaTeX code that generates a standalone
     /// PDF with the plot in a default axis and picture environment.
@@ -167,6 +570,7 @@ impl Plot2D {
     /// assert_eq!(
     /// "\\documentclass{standalone}
     /// \\usepackage{pgfplots}
+    /// \\pgfplotsset{compat=1.16}
     /// \\begin{document}
     /// \\begin{tikzpicture}
     /// \\begin{axis}
@@ -178,9 +582,25 @@ impl Plot2D {
     /// plot.standalone_string());
     /// ```
     pub fn standalone_string(&self) -> String {
-        let mut axis = Axis::new();
-        axis.plots.push(self.clone());
-        axis.standalone_string()
+        Standalone::Plot2D(self).to_string()
+    }
+    /// Write the same LaTeX code returned by [`Plot2D::standalone_string`] into
+    /// `writer`. The code is streamed as it is generated, so no intermediate
+    /// [`String`] is built. You probably want to wrap unbuffered writers (e.g. a
+    /// [`std::fs::File`]) in a [`std::io::BufWriter`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot2D;
+    /// use std::{fs::File, io::BufWriter};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// let file = File::create("figure.tex").unwrap();
+    /// plot.write_standalone(BufWriter::new(file)).unwrap();
+    /// ```
+    pub fn write_standalone<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        Standalone::Plot2D(self).write(writer)
     }
     /// Show the plot in a default [`Axis`] and [`Picture`] as a standalone PDF.
     /// This will create a file in the location returned by
@@ -197,10 +617,700 @@ impl Plot2D {
     /// ```
     #[cfg(feature = "inclusive")]
     pub fn show(&self) -> Result<(), ShowPdfError> {
-        let mut axis = Axis::new();
-        axis.plots.push(self.clone());
-        axis.show()
+        self.show_with(&Tectonic)
+    }
+    /// Same as [`Plot2D::show`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot2D, compiler::Engine};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.show_with(&Engine::LuaLatex);
+    /// ```
+    pub fn show_with<C: Compiler + ?Sized>(&self, compiler: &C) -> Result<(), ShowPdfError> {
+        Standalone::Plot2D(self).show_with(compiler)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// PDF and return its raw bytes. This does not create any file nor open a
+    /// PDF viewer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// let pdf_data = plot.to_pdf_bytes().unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn to_pdf_bytes(&self) -> Result<Vec<u8>, ShowPdfError> {
+        self.to_pdf_bytes_with(&Tectonic)
+    }
+    /// Same as [`Plot2D::to_pdf_bytes`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot2D, compiler::Engine};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// let pdf_data = plot.to_pdf_bytes_with(&Engine::PdfLatex).unwrap();
+    /// ```
+    pub fn to_pdf_bytes_with<C: Compiler + ?Sized>(
+        &self,
+        compiler: &C,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        Standalone::Plot2D(self).to_pdf_bytes_with(compiler)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// PDF and write it to `path`. If the file already exists, it will be
+    /// overwritten. Unlike [`Plot2D::show`], this will not open a PDF viewer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.save_pdf("figure.pdf").unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn save_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), ShowPdfError> {
+        self.save_pdf_with(&Tectonic, path)
+    }
+    /// Same as [`Plot2D::save_pdf`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot2D, compiler::Engine};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.save_pdf_with(&Engine::XeLatex, "figure.pdf").unwrap();
+    /// ```
+    pub fn save_pdf_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        &self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        Standalone::Plot2D(self).save_pdf_with(compiler, path)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// SVG image and return its contents. The image is converted from the same
+    /// PDF document generated by [`Plot2D::to_pdf_bytes`], so both look exactly
+    /// the same.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `dvisvgm` (included in most LaTeX
+    /// distributions) to be available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// let svg_data = plot.to_svg().unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn to_svg(&self) -> Result<String, ShowPdfError> {
+        self.to_svg_with(&Tectonic)
+    }
+    /// Same as [`Plot2D::to_svg`], but the PDF is compiled with the given
+    /// [`Compiler`] before converting it into an SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot2D, compiler::Engine};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// let svg_data = plot.to_svg_with(&Engine::PdfLatex).unwrap();
+    /// ```
+    pub fn to_svg_with<C: Compiler + ?Sized>(&self, compiler: &C) -> Result<String, ShowPdfError> {
+        Standalone::Plot2D(self).to_svg_with(compiler)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// SVG image and write it to `path`. If the file already exists, it will be
+    /// overwritten.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `dvisvgm` (included in most LaTeX
+    /// distributions) to be available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot2D;
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.save_svg("figure.svg").unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn save_svg<P: AsRef<Path>>(&self, path: P) -> Result<(), ShowPdfError> {
+        self.save_svg_with(&Tectonic, path)
+    }
+    /// Same as [`Plot2D::save_svg`], but the PDF is compiled with the given
+    /// [`Compiler`] before converting it into an SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot2D, compiler::Engine};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// plot.save_svg_with(&Engine::XeLatex, "figure.svg").unwrap();
+    /// ```
+    pub fn save_svg_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        &self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        Standalone::Plot2D(self).save_svg_with(compiler, path)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// PNG image and return its raw bytes. The image is rasterized from the
+    /// same PDF document generated by [`Plot2D::to_pdf_bytes`] with a
+    /// resolution of `dpi` dots per inch.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `pdftocairo` (included in Poppler) to be
+    /// available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot2D, compiler::Background};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// let png_data = plot.to_png(300, Background::White).unwrap();
+    /// ```
+    #[cfg(all(feature = "inclusive", feature = "png"))]
+    pub fn to_png(&self, dpi: u32, background: Background) -> Result<Vec<u8>, ShowPdfError> {
+        self.to_png_with(&Tectonic, dpi, background)
+    }
+    /// Same as [`Plot2D::to_png`], but the PDF is compiled with the given
+    /// [`Compiler`] before rasterizing it into a PNG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot2D, compiler::{Background, Engine}};
+    ///
+    /// let mut plot = Plot2D::new();
+    /// let png_data = plot
+    ///     .to_png_with(&Engine::PdfLatex, 150, Background::Transparent)
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "png")]
+    pub fn to_png_with<C: Compiler + ?Sized>(
+        &self,
+        compiler: &C,
+        dpi: u32,
+        background: Background,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        Standalone::Plot2D(self).to_png_with(compiler, dpi, background)
+    }
+}
+
+/// Three-dimensional plot inside an [`Axis`].
+///
+/// Adding a [`Plot3D`] to an [`Axis`] environment is equivalent to:
+///
+/// ```text
+/// \addplot3[PlotKeys]
+///     % coordinates;
+/// ```
+///
+/// # Examples
+///
+/// ```no_run
+/// use pgfplots::axis::plot::{Plot3D, PlotKey, Type3D};
+///
+/// let x: Vec<f64> = (-10..=10).map(f64::from).collect();
+/// let mut plot = Plot3D::from_grid(&x, &x, |x, y| x * x - y * y);
+/// plot.add_key(PlotKey::Type3D(Type3D::Surf));
+///
+/// # #[cfg(feature = "inclusive")]
+/// plot.show();
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Plot3D {
+    keys: Vec<PlotKey>,
+    pub coordinates: Vec<Coordinate3D>,
+    /// Format of the numbers in the coordinates. If [`None`], the
+    /// [`Picture::number_format`] is used (or the default format if the plot is
+    /// not inside a [`Picture`]).
+    pub number_format: Option<NumberFormat>,
+}
+
+impl fmt::Display for Plot3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl Plot3D {
+    /// Same as [`fmt::Display`], but `number_format` is used for the
+    /// coordinates unless the plot has its own [`Plot3D::number_format`].
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        let number_format = self.number_format.unwrap_or(number_format);
+
+        fmt_addplot(f, "addplot3", &self.keys)?;
+        writeln!(f, " coordinates {{")?;
+
+        for coordinate in self.coordinates.iter() {
+            write!(f, "\t\t")?;
+            coordinate.fmt_with(f, number_format)?;
+            writeln!(f)?;
+        }
+
+        write!(f, "\t}};")?;
+
+        Ok(())
+    }
+}
+
+impl Plot3D {
+    /// Creates a new, empty three-dimensional plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates a three-dimensional plot with a mesh by evaluating `z` at every
+    /// point of the grid spanned by `x` and `y`.
+    ///
+    /// The coordinates are sorted in scanlines: there is one scanline for
+    /// each value in `y`, along which the value in `x` varies. The
+    /// [`PlotKey::MeshRows`] and [`PlotKey::MeshCols`] keys are set
+    /// accordingly.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let (x, y) = ([0.0, 1.0, 2.0], [0.0, 1.0]);
+    /// let plot = Plot3D::from_grid(&x, &y, |x, y| x + y);
+    /// assert_eq!(plot.coordinates.len(), 6);
+    /// assert_eq!(plot.coordinates[1].x, 1.0);
+    /// assert_eq!(plot.coordinates[3].y, 1.0);
+    /// ```
+    pub fn from_grid<F: Fn(f64, f64) -> f64>(x: &[f64], y: &[f64], z: F) -> Self {
+        let mut plot = Plot3D::new();
+        plot.coordinates = y
+            .iter()
+            .flat_map(|&y| x.iter().map(move |&x| (x, y)))
+            .map(|(x, y)| (x, y, z(x, y)).into())
+            .collect();
+        plot.add_key(PlotKey::MeshRows(y.len()));
+        plot.add_key(PlotKey::MeshCols(x.len()));
+
+        plot
+    }
+    /// Add a key to control the appearance of the plot. This will overwrite
+    /// any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{Plot3D, PlotKey, Type3D::Mesh};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.add_key(PlotKey::Type3D(Mesh));
+    /// ```
+    pub fn add_key(&mut self, key: PlotKey) {
+        add_key(&mut self.keys, key);
+    }
+    /// Write the plot such that the coordinates are read from the data file at
+    /// `path` (as written by [`Plot3D::write_table`]) instead of being inlined.
+    pub(crate) fn fmt_table(&self, f: &mut fmt::Formatter<'_>, path: &str) -> fmt::Result {
+        fmt_addplot(f, "addplot3", &self.keys)?;
+        write!(f, " table")?;
+        if self.has_point_meta() {
+            write!(f, "[meta=meta]")?;
+        }
+        write!(f, " {{{path}}};")
+    }
+    /// Whether any of the coordinates has point meta data.
+    fn has_point_meta(&self) -> bool {
+        self.coordinates.iter().any(|c| c.point_meta.is_some())
+    }
+    /// Write the coordinates as a whitespace separated table with a header
+    /// row.
+    pub(crate) fn write_table<W: io::Write>(
+        &self,
+        mut writer: W,
+        number_format: NumberFormat,
+    ) -> io::Result<()> {
+        let number_format = self.number_format.unwrap_or(number_format);
+        let point_meta = self.has_point_meta();
+        if point_meta {
+            writeln!(writer, "x y z meta")?;
+        } else {
+            writeln!(writer, "x y z")?;
+        }
+
+        for coordinate in self.coordinates.iter() {
+            let x = number_format.display(coordinate.x);
+            let y = number_format.display(coordinate.y);
+            let z = number_format.display(coordinate.z);
+            write!(writer, "{x} {y} {z}")?;
+            if point_meta {
+                write_point_meta(&mut writer, &coordinate.point_meta, number_format)?;
+            }
+            writeln!(writer)?;
+        }
+
+        writer.flush()
+    }
+    /// Add the packages and libraries needed by the plot to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        for key in self.keys.iter() {
+            key.add_requirements(preamble);
+        }
+    }
+    /// Return a [`String`] with valid LaTeX code that generates a standalone
+    /// PDF with the plot in a default axis and picture environment.
+    ///
+    /// # Note
+    ///
+    /// Passing this string directly to e.g. `pdflatex` will fail to generate a
+    /// PDF document. It is usually necessary to [`str::replace`] all the
+    /// occurrences of `\n` and `\t` with white space before sending this string
+    /// as an argument to a LaTeX compiler.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// assert_eq!(
+    /// "\\documentclass{standalone}
+    /// \\usepackage{pgfplots}
+    /// \\pgfplotsset{compat=1.16}
+    /// \\begin{document}
+    /// \\begin{tikzpicture}
+    /// \\begin{axis}
+    /// \t\\addplot3[] coordinates {
+    /// \t};
+    /// \\end{axis}
+    /// \\end{tikzpicture}
+    /// \\end{document}",
+    /// plot.standalone_string());
+    /// ```
+    pub fn standalone_string(&self) -> String {
+        Standalone::Plot3D(self).to_string()
+    }
+    /// Write the same LaTeX code returned by [`Plot3D::standalone_string`] into
+    /// `writer`. The code is streamed as it is generated, so no intermediate
+    /// [`String`] is built. You probably want to wrap unbuffered writers (e.g. a
+    /// [`std::fs::File`]) in a [`std::io::BufWriter`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot3D;
+    /// use std::{fs::File, io::BufWriter};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// let file = File::create("figure.tex").unwrap();
+    /// plot.write_standalone(BufWriter::new(file)).unwrap();
+    /// ```
+    pub fn write_standalone<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        Standalone::Plot3D(self).write(writer)
+    }
+    /// Show the plot in a default [`Axis`] and [`Picture`] as a standalone PDF.
+    /// This will create a file in the location returned by
+    /// [`std::env::temp_dir()`] and open it with the default PDF viewer in your
+    /// system.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.show();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn show(&self) -> Result<(), ShowPdfError> {
+        self.show_with(&Tectonic)
+    }
+    /// Same as [`Plot3D::show`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot3D, compiler::Engine};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.show_with(&Engine::LuaLatex);
+    /// ```
+    pub fn show_with<C: Compiler + ?Sized>(&self, compiler: &C) -> Result<(), ShowPdfError> {
+        Standalone::Plot3D(self).show_with(compiler)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// PDF and return its raw bytes. This does not create any file nor open a
+    /// PDF viewer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// let pdf_data = plot.to_pdf_bytes().unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn to_pdf_bytes(&self) -> Result<Vec<u8>, ShowPdfError> {
+        self.to_pdf_bytes_with(&Tectonic)
+    }
+    /// Same as [`Plot3D::to_pdf_bytes`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot3D, compiler::Engine};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// let pdf_data = plot.to_pdf_bytes_with(&Engine::PdfLatex).unwrap();
+    /// ```
+    pub fn to_pdf_bytes_with<C: Compiler + ?Sized>(
+        &self,
+        compiler: &C,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        Standalone::Plot3D(self).to_pdf_bytes_with(compiler)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// PDF and write it to `path`. If the file already exists, it will be
+    /// overwritten. Unlike [`Plot3D::show`], this will not open a PDF viewer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.save_pdf("figure.pdf").unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn save_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), ShowPdfError> {
+        self.save_pdf_with(&Tectonic, path)
+    }
+    /// Same as [`Plot3D::save_pdf`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot3D, compiler::Engine};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.save_pdf_with(&Engine::XeLatex, "figure.pdf").unwrap();
+    /// ```
+    pub fn save_pdf_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        &self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        Standalone::Plot3D(self).save_pdf_with(compiler, path)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// SVG image and return its contents. The image is converted from the same
+    /// PDF document generated by [`Plot3D::to_pdf_bytes`], so both look exactly
+    /// the same.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `dvisvgm` (included in most LaTeX
+    /// distributions) to be available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// let svg_data = plot.to_svg().unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn to_svg(&self) -> Result<String, ShowPdfError> {
+        self.to_svg_with(&Tectonic)
     }
+    /// Same as [`Plot3D::to_svg`], but the PDF is compiled with the given
+    /// [`Compiler`] before converting it into an SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot3D, compiler::Engine};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// let svg_data = plot.to_svg_with(&Engine::PdfLatex).unwrap();
+    /// ```
+    pub fn to_svg_with<C: Compiler + ?Sized>(&self, compiler: &C) -> Result<String, ShowPdfError> {
+        Standalone::Plot3D(self).to_svg_with(compiler)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// SVG image and write it to `path`. If the file already exists, it will be
+    /// overwritten.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `dvisvgm` (included in most LaTeX
+    /// distributions) to be available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::axis::plot::Plot3D;
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.save_svg("figure.svg").unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn save_svg<P: AsRef<Path>>(&self, path: P) -> Result<(), ShowPdfError> {
+        self.save_svg_with(&Tectonic, path)
+    }
+    /// Same as [`Plot3D::save_svg`], but the PDF is compiled with the given
+    /// [`Compiler`] before converting it into an SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot3D, compiler::Engine};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// plot.save_svg_with(&Engine::XeLatex, "figure.svg").unwrap();
+    /// ```
+    pub fn save_svg_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        &self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        Standalone::Plot3D(self).save_svg_with(compiler, path)
+    }
+    /// Compile the plot in a default [`Axis`] and [`Picture`] as a standalone
+    /// PNG image and return its raw bytes. The image is rasterized from the
+    /// same PDF document generated by [`Plot3D::to_pdf_bytes`] with a
+    /// resolution of `dpi` dots per inch.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `pdftocairo` (included in Poppler) to be
+    /// available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot3D, compiler::Background};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// let png_data = plot.to_png(300, Background::White).unwrap();
+    /// ```
+    #[cfg(all(feature = "inclusive", feature = "png"))]
+    pub fn to_png(&self, dpi: u32, background: Background) -> Result<Vec<u8>, ShowPdfError> {
+        self.to_png_with(&Tectonic, dpi, background)
+    }
+    /// Same as [`Plot3D::to_png`], but the PDF is compiled with the given
+    /// [`Compiler`] before rasterizing it into a PNG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::plot::Plot3D, compiler::{Background, Engine}};
+    ///
+    /// let mut plot = Plot3D::new();
+    /// let png_data = plot
+    ///     .to_png_with(&Engine::PdfLatex, 150, Background::Transparent)
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "png")]
+    pub fn to_png_with<C: Compiler + ?Sized>(
+        &self,
+        compiler: &C,
+        dpi: u32,
+        background: Background,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        Standalone::Plot3D(self).to_png_with(compiler, dpi, background)
+    }
+}
+
+/// Write the `\command[PlotKeys]` part of a plot without any coordinates.
+fn fmt_addplot(f: &mut fmt::Formatter<'_>, command: &str, keys: &[PlotKey]) -> fmt::Result {
+    write!(f, "\t\\{command}[")?;
+    // If there are keys, print them one per line. It makes it easier for a
+    // human to find individual keys later.
+    if !keys.is_empty() {
+        writeln!(f)?;
+        for key in keys.iter() {
+            writeln!(f, "\t\t{key},")?;
+        }
+        write!(f, "\t")?;
+    }
+    write!(f, "]")
+}
+
+/// Write a point meta column of a data table (preceded by a space). Missing
+/// point meta data is written as `nan`.
+fn write_point_meta<W: io::Write>(
+    writer: &mut W,
+    point_meta: &Option<PointMeta>,
+    number_format: NumberFormat,
+) -> io::Result<()> {
+    match point_meta {
+        Some(PointMeta::Numeric(value)) => write!(writer, " {}", number_format.display(*value)),
+        // Braced because the columns are separated by white space.
+        Some(PointMeta::Symbolic(value)) => write!(writer, " {{{value}}}"),
+        None => write!(writer, " nan"),
+    }
+}
+
+/// Return the `p`-quantile (`0 <= p <= 1`) of the non-empty `sorted` samples,
+/// linearly interpolated between the closest ranks.
+fn quantile(sorted: &[f64], p: f64) -> f64 {
+    let rank = p * (sorted.len() - 1) as f64;
+    let (low, high) = (rank.floor() as usize, rank.ceil() as usize);
+    sorted[low] + (sorted[high] - sorted[low]) * (rank - low as f64)
+}
+
+/// Add `key` to `keys`, removing any previous mutually exclusive key.
+fn add_key(keys: &mut Vec<PlotKey>, key: PlotKey) {
+    match key {
+        PlotKey::Custom(_) => (),
+        _ => {
+            if let Some(index) = keys
+                .iter()
+                .position(|k| std::mem::discriminant(k) == std::mem::discriminant(&key))
+            {
+                keys.remove(index);
+            }
+        }
+    }
+    keys.push(key);
 }
 
 /// Control the type of two dimensional plots.
@@ -232,30 +1342,34 @@ pub enum Type2D {
     /// `bar_width` field controls the width of the horizontal bars, and
     /// `bar_shift` controls the vertical shift. Unless you are plotting
     /// multiple bars in the same [`Axis`], you most likely want
-    /// `bar_shift: 0.0`.
+    /// `bar_shift: Length::pt(0.0)`.
     ///
     /// # Note
     ///
-    /// By default, `bar_width` and `bar_shift` are assumed to be in `pt` units.
-    /// If you want them to be interpreted as axis units (this is most likely
-    /// what you want), you need to add the plot to an [`Axis`], add the
+    /// Lengths in [`Unit::Axis`] are interpreted as axis units (this is most
+    /// likely what you want) only if you add the plot to an [`Axis`], add the
     /// [`Axis`] to a [`Picture`], and set `compat=1.7` or higher on the
-    /// [`Picture`].
-    XBar { bar_width: f64, bar_shift: f64 },
+    /// [`Picture`]. Otherwise, they are assumed to be in `pt` units.
+    XBar {
+        bar_width: Length,
+        bar_shift: Length,
+    },
     /// Draw vertical bars between the *x = 0* line and each coordinate. The
     /// `bar_width` field controls the width of the vertical bars, and
     /// `bar_shift` controls the horizontal shift. Unless you are plotting
     /// multiple bars in the same [`Axis`], you most likely want
-    /// `bar_shift: 0.0`.
+    /// `bar_shift: Length::pt(0.0)`.
     ///
     /// # Note
     ///
-    /// By default, `bar_width` and `bar_shift` are assumed to be in `pt` units.
-    /// If you want them to be interpreted as axis units (this is most likely
-    /// what you want), you need to add the plot to an [`Axis`], add the
+    /// Lengths in [`Unit::Axis`] are interpreted as axis units (this is most
+    /// likely what you want) only if you add the plot to an [`Axis`], add the
     /// [`Axis`] to a [`Picture`], and set `compat=1.7` or higher on the
-    /// [`Picture`].
-    YBar { bar_width: f64, bar_shift: f64 },
+    /// [`Picture`]. Otherwise, they are assumed to be in `pt` units.
+    YBar {
+        bar_width: Length,
+        bar_shift: Length,
+    },
     /// Similar to [`Type2D::XBar`] except that it draws a single horizontal
     /// lines instead of rectangles.
     XComb,
@@ -264,6 +1378,10 @@ pub enum Type2D {
     YComb,
     /// Draw only markers.
     OnlyMarks,
+    /// Draw vertical bars between successive coordinates, as in a histogram.
+    /// The height of each bar is the *y* value of its left coordinate, so the
+    /// *y* value of the last coordinate is ignored.
+    YBarInterval,
 }
 impl fmt::Display for Type2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -287,6 +1405,80 @@ impl fmt::Display for Type2D {
             Type2D::XComb => write!(f, "xcomb"),
             Type2D::YComb => write!(f, "ycomb"),
             Type2D::OnlyMarks => write!(f, "only marks"),
+            Type2D::YBarInterval => write!(f, "ybar interval"),
+        }
+    }
+}
+
+impl Type2D {
+    /// Add the packages and libraries needed by the plot type to the
+    /// `preamble`.
+    fn add_requirements(&self, preamble: &mut Preamble) {
+        // The other plot types only need PGFPlots itself.
+        if let Type2D::XBar {
+            bar_width,
+            bar_shift,
+        }
+        | Type2D::YBar {
+            bar_width,
+            bar_shift,
+        } = self
+        {
+            bar_width.add_requirements(preamble);
+            bar_shift.add_requirements(preamble);
+        }
+    }
+}
+
+/// Control the type of three dimensional plots.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Type3D {
+    /// Surface with faces colored according to the colormap.
+    Surf,
+    /// Mesh with edges colored according to the colormap.
+    Mesh,
+    /// Individual markers colored according to the colormap.
+    Scatter,
+    /// Mesh with black edges.
+    Wireframe,
+}
+impl fmt::Display for Type3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Type3D::Surf => write!(f, "surf"),
+            Type3D::Mesh => write!(f, "mesh"),
+            Type3D::Scatter => write!(f, "scatter, only marks"),
+            Type3D::Wireframe => write!(f, "mesh, draw=black"),
+        }
+    }
+}
+
+/// Control the source of the point meta data.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum PointMetaSource {
+    /// Read the numeric [`Coordinate2D::point_meta`] (or
+    /// [`Coordinate3D::point_meta`]) of each coordinate.
+    Explicit,
+    /// Read the symbolic [`Coordinate2D::point_meta`] (or
+    /// [`Coordinate3D::point_meta`]) of each coordinate.
+    ExplicitSymbolic,
+    /// Use the *x* coordinate.
+    X,
+    /// Use the *y* coordinate.
+    Y,
+    /// Use the *z* coordinate.
+    Z,
+}
+impl fmt::Display for PointMetaSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PointMetaSource::Explicit => write!(f, "explicit"),
+            PointMetaSource::ExplicitSymbolic => write!(f, "explicit symbolic"),
+            PointMetaSource::X => write!(f, "x"),
+            PointMetaSource::Y => write!(f, "y"),
+            PointMetaSource::Z => write!(f, "z"),
         }
     }
 }
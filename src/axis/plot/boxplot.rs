@@ -0,0 +1,305 @@
+use crate::axis::plot::{add_key, fmt_addplot, quantile, PlotKey};
+use crate::{number::NumberFormat, preamble::Preamble};
+use std::{fmt, io};
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{axis::Axis, Picture};
+
+/// Box plot of one-dimensional samples inside an [`Axis`].
+///
+/// The median, quartiles, whiskers and outliers are computed from the samples
+/// (see [`BoxPlot::statistics`]); non-finite samples are ignored. The outliers
+/// are drawn as marks. If there are no samples, nothing is plotted.
+///
+/// Adding a [`BoxPlot`] to an [`Axis`] environment is equivalent to:
+///
+/// ```text
+/// \addplot+[boxplot prepared={statistics}, PlotKeys]
+///     % outliers;
+/// ```
+///
+/// This requires the `statistics` PGFPlots library, which is added to the
+/// [`Picture::preamble`] automatically.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{plot::boxplot::BoxPlot, Axis};
+///
+/// let mut axis = Axis::new();
+/// // Boxes are placed at 1, 2, ... unless they have an explicit position.
+/// axis.plots.push(BoxPlot::from_samples(vec![1.0, 2.0, 2.5, 3.0, 9.0]).into());
+/// axis.plots.push(BoxPlot::from_samples(vec![2.0, 3.0, 3.5, 4.0]).into());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct BoxPlot {
+    keys: Vec<PlotKey>,
+    /// Samples of the distribution.
+    pub samples: Vec<f64>,
+    /// How the whiskers are computed. Defaults to [`Whiskers::Tukey`].
+    pub whiskers: Whiskers,
+    /// Whether the box is drawn vertically or horizontally. Defaults to
+    /// [`Orientation::Vertical`].
+    pub orientation: Orientation,
+    /// Position of the box along the axis perpendicular to the
+    /// [`BoxPlot::orientation`]. If [`None`], the boxes in an [`Axis`] are
+    /// placed at 1, 2, 3, ... in the order in which they are added.
+    pub position: Option<f64>,
+    /// Format of the numbers in the statistics and outliers. If [`None`], the
+    /// [`Picture::number_format`] is used (or the default format if the plot is
+    /// not inside a [`Picture`]).
+    pub number_format: Option<NumberFormat>,
+}
+
+impl fmt::Display for BoxPlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl BoxPlot {
+    /// Same as [`fmt::Display`], but `number_format` is used for the numbers
+    /// unless the plot has its own [`BoxPlot::number_format`].
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        let number_format = self.number_format.unwrap_or(number_format);
+
+        fmt_addplot(f, "addplot+", &self.all_keys(number_format))?;
+        writeln!(f, " coordinates {{")?;
+
+        for (x, y) in self.outliers() {
+            let (x, y) = (number_format.display(x), number_format.display(y));
+            writeln!(f, "\t\t({x},{y})")?;
+        }
+
+        write!(f, "\t}};")?;
+
+        Ok(())
+    }
+}
+
+impl BoxPlot {
+    /// Creates a new, empty box plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::boxplot::BoxPlot;
+    ///
+    /// let mut boxplot = BoxPlot::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates a box plot of `samples`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::boxplot::BoxPlot;
+    ///
+    /// let boxplot = BoxPlot::from_samples(vec![1.0, 2.0, 2.0, 3.0]);
+    /// assert_eq!(boxplot.samples.len(), 4);
+    /// ```
+    pub fn from_samples(samples: Vec<f64>) -> Self {
+        BoxPlot {
+            samples,
+            ..Default::default()
+        }
+    }
+    /// Add a key to control the appearance of the plot. This will overwrite
+    /// any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{boxplot::BoxPlot, PlotKey};
+    ///
+    /// let mut boxplot = BoxPlot::new();
+    /// boxplot.add_key(PlotKey::Custom(String::from("fill=gray")));
+    /// ```
+    pub fn add_key(&mut self, key: PlotKey) {
+        add_key(&mut self.keys, key);
+    }
+    /// Return the statistics drawn by the box plot, or [`None`] if there are
+    /// no finite samples. Quartiles are linearly interpolated between the
+    /// closest samples.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::boxplot::BoxPlot;
+    ///
+    /// let boxplot = BoxPlot::from_samples(vec![1.0, 2.0, 3.0, 4.0, 5.0, 20.0]);
+    /// let statistics = boxplot.statistics().unwrap();
+    /// assert_eq!(statistics.median, 3.5);
+    /// assert_eq!(statistics.upper_whisker, 5.0);
+    /// assert_eq!(statistics.outliers, vec![20.0]);
+    /// ```
+    pub fn statistics(&self) -> Option<Statistics> {
+        let mut sorted: Vec<f64> = self
+            .samples
+            .iter()
+            .copied()
+            .filter(|sample| sample.is_finite())
+            .collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_by(f64::total_cmp);
+
+        let lower_quartile = quantile(&sorted, 0.25);
+        let upper_quartile = quantile(&sorted, 0.75);
+        let (lower_fence, upper_fence) = match self.whiskers {
+            Whiskers::Tukey => {
+                let range = 1.5 * (upper_quartile - lower_quartile);
+                (lower_quartile - range, upper_quartile + range)
+            }
+            Whiskers::MinMax => (f64::NEG_INFINITY, f64::INFINITY),
+        };
+        let (inside, outliers): (Vec<f64>, Vec<f64>) = sorted
+            .iter()
+            .partition(|&&sample| lower_fence <= sample && sample <= upper_fence);
+
+        Some(Statistics {
+            median: quantile(&sorted, 0.5),
+            lower_quartile,
+            upper_quartile,
+            // The quartiles are always inside the fences, so there is at
+            // least one sample inside.
+            lower_whisker: inside[0],
+            upper_whisker: inside[inside.len() - 1],
+            outliers,
+        })
+    }
+    /// Return the `(x, y)` coordinates of the outliers.
+    fn outliers(&self) -> Vec<(f64, f64)> {
+        let outliers = self.statistics().map_or(Vec::new(), |s| s.outliers);
+        // The position of the outliers is set by `boxplot prepared`.
+        outliers
+            .into_iter()
+            .map(|outlier| match self.orientation {
+                Orientation::Vertical => (0.0, outlier),
+                Orientation::Horizontal => (outlier, 0.0),
+            })
+            .collect()
+    }
+    /// Return the keys written in the options of the `\addplot` command i.e.
+    /// the box plot keys overwritten by the user keys.
+    fn all_keys(&self, number_format: NumberFormat) -> Vec<PlotKey> {
+        let mut keys = Vec::new();
+        if let Some(statistics) = self.statistics() {
+            let number = |value: f64| number_format.display(value).to_string();
+            let mut options = vec![
+                format!("lower whisker={}", number(statistics.lower_whisker)),
+                format!("lower quartile={}", number(statistics.lower_quartile)),
+                format!("median={}", number(statistics.median)),
+                format!("upper quartile={}", number(statistics.upper_quartile)),
+                format!("upper whisker={}", number(statistics.upper_whisker)),
+            ];
+            if let Some(position) = self.position {
+                options.push(format!("draw position={}", number(position)));
+            }
+            if let Orientation::Horizontal = self.orientation {
+                options.push(String::from("draw direction=x"));
+            }
+            keys.push(PlotKey::Custom(format!(
+                "boxplot prepared={{{}}}",
+                options.join(", ")
+            )));
+        }
+        for key in self.keys.iter() {
+            add_key(&mut keys, key.clone());
+        }
+
+        keys
+    }
+    /// Write the plot such that the outliers are read from the data file at
+    /// `path` (as written by [`BoxPlot::write_table`]) instead of being
+    /// inlined.
+    pub(crate) fn fmt_table(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        path: &str,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        let number_format = self.number_format.unwrap_or(number_format);
+
+        fmt_addplot(f, "addplot+", &self.all_keys(number_format))?;
+        write!(f, " table {{{path}}};")
+    }
+    /// Write the outliers as a whitespace separated table with a header row.
+    pub(crate) fn write_table<W: io::Write>(
+        &self,
+        mut writer: W,
+        number_format: NumberFormat,
+    ) -> io::Result<()> {
+        let number_format = self.number_format.unwrap_or(number_format);
+        writeln!(writer, "x y")?;
+
+        for (x, y) in self.outliers() {
+            let (x, y) = (number_format.display(x), number_format.display(y));
+            writeln!(writer, "{x} {y}")?;
+        }
+
+        writer.flush()
+    }
+    /// Add the packages and libraries needed by the plot to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        preamble.add_pgfplots_library("statistics");
+        for key in self.keys.iter() {
+            key.add_requirements(preamble);
+        }
+    }
+}
+
+/// Statistics of the samples of a [`BoxPlot`].
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct Statistics {
+    /// Median of the samples.
+    pub median: f64,
+    /// First quartile of the samples i.e. the bottom of the box.
+    pub lower_quartile: f64,
+    /// Third quartile of the samples i.e. the top of the box.
+    pub upper_quartile: f64,
+    /// End of the lower whisker.
+    pub lower_whisker: f64,
+    /// End of the upper whisker.
+    pub upper_whisker: f64,
+    /// Samples outside of the whiskers, in increasing order.
+    pub outliers: Vec<f64>,
+}
+
+/// Control how the whiskers of a [`BoxPlot`] are computed.
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum Whiskers {
+    /// Whiskers extend to the most extreme samples within 1.5 times the
+    /// interquartile range from the box. Samples beyond the whiskers are
+    /// outliers.
+    #[default]
+    Tukey,
+    /// Whiskers extend to the minimum and maximum samples. There are no
+    /// outliers.
+    MinMax,
+}
+
+/// Control the orientation of a [`BoxPlot`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum Orientation {
+    /// The samples are along the *y* axis.
+    #[default]
+    Vertical,
+    /// The samples are along the *x* axis.
+    Horizontal,
+}
+
+#[cfg(test)]
+mod tests;
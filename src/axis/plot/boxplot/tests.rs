@@ -0,0 +1,123 @@
+use super::*;
+
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn whiskers_tested() {
+    let whiskers = Whiskers::Tukey;
+    match whiskers {
+        Whiskers::Tukey => (),
+        Whiskers::MinMax => (),
+    }
+}
+
+#[test]
+fn orientations_tested() {
+    let orientation = Orientation::Vertical;
+    match orientation {
+        Orientation::Vertical => (),
+        Orientation::Horizontal => (),
+    }
+}
+
+#[test]
+fn boxplot_new() {
+    let boxplot = BoxPlot::new();
+    assert!(boxplot.keys.is_empty());
+    assert!(boxplot.samples.is_empty());
+    assert!(matches!(boxplot.whiskers, Whiskers::Tukey));
+    assert!(matches!(boxplot.orientation, Orientation::Vertical));
+    assert!(boxplot.position.is_none());
+    assert!(boxplot.number_format.is_none());
+    assert!(boxplot.statistics().is_none());
+}
+
+#[test]
+fn boxplot_statistics_tukey() {
+    let boxplot = BoxPlot::from_samples(vec![-10.0, 2.0, 1.0, 3.0, f64::NAN, 4.0, 5.0, 20.0]);
+    // Sorted finite samples: -10 1 2 3 4 5 20
+    assert_eq!(
+        boxplot.statistics(),
+        Some(Statistics {
+            median: 3.0,
+            lower_quartile: 1.5,
+            upper_quartile: 4.5,
+            lower_whisker: 1.0,
+            upper_whisker: 5.0,
+            outliers: vec![-10.0, 20.0],
+        })
+    );
+}
+
+#[test]
+fn boxplot_statistics_min_max() {
+    let mut boxplot = BoxPlot::from_samples(vec![-10.0, 1.0, 2.0, 3.0, 4.0, 5.0, 20.0]);
+    boxplot.whiskers = Whiskers::MinMax;
+    let statistics = boxplot.statistics().unwrap();
+    assert_eq!(statistics.lower_whisker, -10.0);
+    assert_eq!(statistics.upper_whisker, 20.0);
+    assert!(statistics.outliers.is_empty());
+}
+
+#[test]
+fn boxplot_statistics_single_sample() {
+    let boxplot = BoxPlot::from_samples(vec![2.0]);
+    assert_eq!(
+        boxplot.statistics(),
+        Some(Statistics {
+            median: 2.0,
+            lower_quartile: 2.0,
+            upper_quartile: 2.0,
+            lower_whisker: 2.0,
+            upper_whisker: 2.0,
+            outliers: Vec::new(),
+        })
+    );
+}
+
+#[test]
+fn boxplot_to_string() {
+    let mut boxplot = BoxPlot::from_samples(vec![1.0, 2.0, 3.0, 4.0, 5.0, 20.0]);
+    assert_eq!(
+        boxplot.to_string(),
+        "\t\\addplot+[\n\t\tboxplot prepared={lower whisker=1, lower quartile=2.25, median=3.5, upper quartile=4.75, upper whisker=5},\n\t] coordinates {\n\t\t(0,20)\n\t};"
+    );
+
+    boxplot.orientation = Orientation::Horizontal;
+    boxplot.position = Some(2.5);
+    boxplot.add_key(PlotKey::Custom(String::from("fill=gray")));
+    assert_eq!(
+        boxplot.to_string(),
+        "\t\\addplot+[\n\t\tboxplot prepared={lower whisker=1, lower quartile=2.25, median=3.5, upper quartile=4.75, upper whisker=5, draw position=2.5, draw direction=x},\n\t\tfill=gray,\n\t] coordinates {\n\t\t(20,0)\n\t};"
+    );
+}
+
+#[test]
+fn boxplot_empty_to_string() {
+    let boxplot = BoxPlot::new();
+    assert_eq!(boxplot.to_string(), "\t\\addplot+[] coordinates {\n\t};");
+}
+
+#[test]
+fn boxplot_write_table() {
+    let boxplot = BoxPlot::from_samples(vec![1.0, 2.0, 3.0, 4.0, 5.0, 20.0]);
+    let mut table = Vec::new();
+    boxplot
+        .write_table(&mut table, NumberFormat::new())
+        .unwrap();
+    assert_eq!(String::from_utf8(table).unwrap(), "x y\n0 20\n");
+}
+
+#[test]
+fn boxplot_add_requirements() {
+    let mut preamble = Preamble::new();
+    BoxPlot::new().add_requirements(&mut preamble);
+    assert!(preamble
+        .to_string()
+        .contains("\\usepgfplotslibrary{statistics}\n"));
+}
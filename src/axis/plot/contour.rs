@@ -0,0 +1,448 @@
+use crate::axis::plot::{add_key, coordinate::Coordinate3D, fmt_addplot, PlotKey};
+use crate::{number::NumberFormat, preamble::Preamble};
+use std::collections::{BTreeMap, BTreeSet};
+use std::{fmt, io};
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{
+    axis::{plot::Plot3D, Axis},
+    compiler::ShellEscape,
+    Picture,
+};
+
+/// Contour plot of a two-dimensional scalar field inside an [`Axis`].
+///
+/// The scalar field is sampled on a rectangular grid: `z[j * x.len() + i]` is
+/// the value at `(x[i], y[j])`. This is the same order used by
+/// [`Plot3D::from_grid`]. If `z` does not have exactly `x.len() * y.len()`
+/// values, nothing is plotted.
+///
+/// Adding a [`ContourPlot`] to an [`Axis`] environment is equivalent to:
+///
+/// ```text
+/// \addplot3[contour prepared, PlotKeys]
+///     % contour lines;
+/// ```
+///
+/// where the contour lines are computed with marching squares. See
+/// [`ContourMethod`] to let PGFPlots compute the contours instead.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{plot::contour::{ContourPlot, Levels}, Axis};
+///
+/// let x: Vec<f64> = (-20..=20).map(|i| f64::from(i) / 10.0).collect();
+/// let mut contour = ContourPlot::from_grid(&x, &x, |x, y| x * x + y * y);
+/// contour.levels = Levels::Values(vec![0.5, 1.0, 2.0]);
+///
+/// let mut axis = Axis::new();
+/// axis.plots.push(contour.into());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ContourPlot {
+    keys: Vec<PlotKey>,
+    /// Coordinates of the columns of the grid.
+    pub x: Vec<f64>,
+    /// Coordinates of the rows of the grid.
+    pub y: Vec<f64>,
+    /// Values of the scalar field at each point of the grid, row by row.
+    pub z: Vec<f64>,
+    /// Levels at which contour lines are drawn. Defaults to 5 levels.
+    pub levels: Levels,
+    /// How the contours are computed. Defaults to
+    /// [`ContourMethod::MarchingSquares`].
+    pub method: ContourMethod,
+    /// Whether the contour lines are labeled with their level. Defaults to
+    /// `true`. Filled contours are never labeled.
+    pub labels: bool,
+    /// Format of the numbers in the coordinates. If [`None`], the
+    /// [`Picture::number_format`] is used (or the default format if the plot is
+    /// not inside a [`Picture`]).
+    pub number_format: Option<NumberFormat>,
+}
+
+impl Default for ContourPlot {
+    fn default() -> Self {
+        ContourPlot {
+            keys: Vec::new(),
+            x: Vec::new(),
+            y: Vec::new(),
+            z: Vec::new(),
+            levels: Levels::default(),
+            method: ContourMethod::default(),
+            labels: true,
+            number_format: None,
+        }
+    }
+}
+
+impl fmt::Display for ContourPlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl ContourPlot {
+    /// Same as [`fmt::Display`], but `number_format` is used for the
+    /// coordinates unless the plot has its own [`ContourPlot::number_format`].
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        let number_format = self.number_format.unwrap_or(number_format);
+
+        fmt_addplot(f, "addplot3", &self.all_keys(number_format))?;
+        writeln!(f, " coordinates {{")?;
+
+        for (i, line) in self.lines().iter().enumerate() {
+            // Empty lines separate the individual contour lines.
+            if i > 0 {
+                writeln!(f)?;
+            }
+            for coordinate in line.iter() {
+                write!(f, "\t\t")?;
+                coordinate.fmt_with(f, number_format)?;
+                writeln!(f)?;
+            }
+        }
+
+        write!(f, "\t}};")?;
+
+        Ok(())
+    }
+}
+
+impl ContourPlot {
+    /// Creates a new, empty contour plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::contour::ContourPlot;
+    ///
+    /// let mut contour = ContourPlot::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates a contour plot by evaluating `z` at every point of the grid
+    /// spanned by `x` and `y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::contour::ContourPlot;
+    ///
+    /// let (x, y) = ([0.0, 1.0, 2.0], [0.0, 1.0]);
+    /// let contour = ContourPlot::from_grid(&x, &y, |x, y| x + y);
+    /// assert_eq!(contour.z, vec![0.0, 1.0, 2.0, 1.0, 2.0, 3.0]);
+    /// ```
+    pub fn from_grid<F: Fn(f64, f64) -> f64>(x: &[f64], y: &[f64], z: F) -> Self {
+        ContourPlot {
+            x: x.to_vec(),
+            y: y.to_vec(),
+            z: y.iter()
+                .flat_map(|&y| x.iter().map(move |&x| (x, y)))
+                .map(|(x, y)| z(x, y))
+                .collect(),
+            ..Default::default()
+        }
+    }
+    /// Add a key to control the appearance of the plot. This will overwrite
+    /// any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{contour::ContourPlot, PlotKey};
+    ///
+    /// let mut contour = ContourPlot::new();
+    /// contour.add_key(PlotKey::Custom(String::from("thick")));
+    /// ```
+    pub fn add_key(&mut self, key: PlotKey) {
+        add_key(&mut self.keys, key);
+    }
+    /// Whether `z` has one value for each point of the grid.
+    fn is_valid(&self) -> bool {
+        self.z.len() == self.x.len() * self.y.len()
+    }
+    /// Return the keys written in the options of the `\addplot3` command i.e.
+    /// the contour key followed by the user keys.
+    fn all_keys(&self, number_format: NumberFormat) -> Vec<PlotKey> {
+        let levels = match &self.levels {
+            Levels::Number(number) => format!("number={number}"),
+            Levels::Values(values) => {
+                let values: Vec<String> = values
+                    .iter()
+                    .map(|v| number_format.display(*v).to_string())
+                    .collect();
+                format!("levels={{{}}}", values.join(","))
+            }
+        };
+        let mut keys = match self.method {
+            ContourMethod::MarchingSquares => vec![PlotKey::Custom(format!(
+                "contour prepared={{labels={}}}",
+                self.labels
+            ))],
+            ContourMethod::Gnuplot => vec![
+                PlotKey::Custom(format!(
+                    "contour gnuplot={{{levels}, labels={}}}",
+                    self.labels
+                )),
+                PlotKey::MeshRows(self.y.len()),
+                PlotKey::MeshCols(self.x.len()),
+            ],
+            ContourMethod::Filled => vec![
+                PlotKey::Custom(format!("contour filled={{{levels}}}")),
+                PlotKey::MeshRows(self.y.len()),
+                PlotKey::MeshCols(self.x.len()),
+            ],
+        };
+        keys.extend(self.keys.iter().cloned());
+
+        keys
+    }
+    /// Return the coordinates to write, split in lines. These are the contour
+    /// lines (with the level as *z* coordinate) if they are computed by us,
+    /// otherwise a single line with the whole grid.
+    fn lines(&self) -> Vec<Vec<Coordinate3D>> {
+        if !self.is_valid() {
+            return Vec::new();
+        }
+        match self.method {
+            ContourMethod::MarchingSquares => self
+                .levels
+                .values(&self.z)
+                .into_iter()
+                .flat_map(|level| {
+                    marching_squares(&self.x, &self.y, &self.z, level)
+                        .into_iter()
+                        .map(move |line| {
+                            line.into_iter()
+                                .map(|(x, y)| (x, y, level).into())
+                                .collect()
+                        })
+                })
+                .collect(),
+            ContourMethod::Gnuplot | ContourMethod::Filled => vec![self
+                .y
+                .iter()
+                .flat_map(|&y| self.x.iter().map(move |&x| (x, y)))
+                .zip(self.z.iter())
+                .map(|((x, y), &z)| (x, y, z).into())
+                .collect()],
+        }
+    }
+    /// Write the plot such that the coordinates are read from the data file at
+    /// `path` (as written by [`ContourPlot::write_table`]) instead of being
+    /// inlined.
+    pub(crate) fn fmt_table(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        path: &str,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        let number_format = self.number_format.unwrap_or(number_format);
+        fmt_addplot(f, "addplot3", &self.all_keys(number_format))?;
+        write!(f, " table {{{path}}};")
+    }
+    /// Write the coordinates as a whitespace separated table with a header
+    /// row. Contour lines are separated by empty lines.
+    pub(crate) fn write_table<W: io::Write>(
+        &self,
+        mut writer: W,
+        number_format: NumberFormat,
+    ) -> io::Result<()> {
+        let number_format = self.number_format.unwrap_or(number_format);
+        writeln!(writer, "x y z")?;
+
+        for (i, line) in self.lines().iter().enumerate() {
+            if i > 0 {
+                writeln!(writer)?;
+            }
+            for coordinate in line.iter() {
+                let x = number_format.display(coordinate.x);
+                let y = number_format.display(coordinate.y);
+                let z = number_format.display(coordinate.z);
+                writeln!(writer, "{x} {y} {z}")?;
+            }
+        }
+
+        writer.flush()
+    }
+    /// Add the packages and libraries needed by the plot to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        match self.method {
+            ContourMethod::MarchingSquares => (),
+            ContourMethod::Gnuplot => (),
+            // `contour filled` was introduced in PGFPlots 1.14.
+            ContourMethod::Filled => preamble.require_compat("1.14"),
+        }
+        for key in self.keys.iter() {
+            key.add_requirements(preamble);
+        }
+    }
+}
+
+/// Control the levels of the contour lines.
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Levels {
+    /// Number of levels. When computed with marching squares, the levels are
+    /// evenly spaced strictly between the minimum and maximum values of the
+    /// scalar field.
+    Number(usize),
+    /// Explicit level values.
+    Values(Vec<f64>),
+}
+
+impl Default for Levels {
+    fn default() -> Self {
+        Levels::Number(5)
+    }
+}
+
+impl Levels {
+    /// Return the level values for the scalar field `z`. Non-finite values of
+    /// the field are ignored.
+    fn values(&self, z: &[f64]) -> Vec<f64> {
+        match self {
+            Levels::Number(number) => {
+                let (min, max) = z
+                    .iter()
+                    .filter(|z| z.is_finite())
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &z| {
+                        (min.min(z), max.max(z))
+                    });
+                if min > max {
+                    return Vec::new();
+                }
+                let step = (max - min) / (*number as f64 + 1.0);
+                (1..=*number).map(|i| min + step * i as f64).collect()
+            }
+            Levels::Values(values) => values.clone(),
+        }
+    }
+}
+
+/// Control how the contours are computed.
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum ContourMethod {
+    /// Compute the contour lines with marching squares and pass them to
+    /// PGFPlots with `contour prepared`.
+    #[default]
+    MarchingSquares,
+    /// Pass the grid to PGFPlots, which computes the contour lines with
+    /// `contour gnuplot`. This requires `gnuplot` to be available in your
+    /// `PATH`, and the LaTeX compiler to run with `-shell-escape` (e.g.
+    /// [`ShellEscape`]).
+    Gnuplot,
+    /// Pass the grid to PGFPlots, which fills the regions between the levels
+    /// with `contour filled`. This requires PGFPlots 1.14 or newer.
+    Filled,
+}
+
+/// Compute the contour lines of the scalar field `z` (sampled on the grid
+/// spanned by `x` and `y`) at the given `level`.
+///
+/// Each cell of the grid contributes segments between the points where the
+/// level crosses its edges. Segments that share an edge point are then joined
+/// into polylines; closed contour lines end with their first point. Cells with
+/// a non-finite corner are skipped.
+fn marching_squares(x: &[f64], y: &[f64], z: &[f64], level: f64) -> Vec<Vec<(f64, f64)>> {
+    let (nx, ny) = (x.len(), y.len());
+    if nx < 2 || ny < 2 || z.len() != nx * ny {
+        return Vec::new();
+    }
+    let at = |i: usize, j: usize| z[j * nx + i];
+    // Every edge of the grid has a unique id. The edge from (i, j) to
+    // (i + 1, j) is 2 * (j * nx + i), and the edge from (i, j) to (i, j + 1)
+    // is 2 * (j * nx + i) + 1.
+    let point = |edge: usize| {
+        let (node, vertical) = (edge / 2, edge % 2 == 1);
+        let (i, j) = (node % nx, node / nx);
+        let (k, l) = if vertical { (i, j + 1) } else { (i + 1, j) };
+        let t = (level - at(i, j)) / (at(k, l) - at(i, j));
+        (x[i] + t * (x[k] - x[i]), y[j] + t * (y[l] - y[j]))
+    };
+
+    // Each edge is crossed at most once, so every edge point is shared by at
+    // most two segments.
+    let mut neighbours: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for j in 0..ny - 1 {
+        for i in 0..nx - 1 {
+            let corners = [at(i, j), at(i + 1, j), at(i + 1, j + 1), at(i, j + 1)];
+            if corners.iter().any(|c| !c.is_finite()) {
+                continue;
+            }
+            let bottom = 2 * (j * nx + i);
+            let right = 2 * (j * nx + i + 1) + 1;
+            let top = 2 * ((j + 1) * nx + i);
+            let left = 2 * (j * nx + i) + 1;
+
+            let case = corners
+                .iter()
+                .enumerate()
+                .fold(0, |case, (bit, &c)| case | (usize::from(c > level) << bit));
+            let center_above = corners.iter().sum::<f64>() / 4.0 > level;
+            let segments = match case {
+                1 | 14 => vec![(left, bottom)],
+                2 | 13 => vec![(bottom, right)],
+                3 | 12 => vec![(left, right)],
+                4 | 11 => vec![(right, top)],
+                6 | 9 => vec![(bottom, top)],
+                7 | 8 => vec![(left, top)],
+                // Saddle points are disambiguated with the average of the
+                // corners.
+                5 if center_above => vec![(bottom, right), (left, top)],
+                10 if !center_above => vec![(bottom, right), (left, top)],
+                5 | 10 => vec![(left, bottom), (right, top)],
+                _ => Vec::new(),
+            };
+            for (a, b) in segments {
+                neighbours.entry(a).or_default().push(b);
+                neighbours.entry(b).or_default().push(a);
+            }
+        }
+    }
+
+    // Start from the ends of open lines, such that they are not split in two.
+    // Whatever is left are closed lines.
+    let starts: Vec<usize> = neighbours
+        .iter()
+        .filter(|(_, n)| n.len() == 1)
+        .map(|(&edge, _)| edge)
+        .chain(neighbours.keys().copied())
+        .collect();
+    let mut visited = BTreeSet::new();
+    let mut lines = Vec::new();
+    for start in starts {
+        if !visited.insert(start) {
+            continue;
+        }
+        let mut line = vec![start];
+        let mut current = start;
+        while let Some(&next) = neighbours[&current]
+            .iter()
+            .find(|edge| !visited.contains(*edge))
+        {
+            visited.insert(next);
+            line.push(next);
+            current = next;
+        }
+        if line.len() > 2 && neighbours[&current].contains(&start) {
+            line.push(start);
+        }
+        lines.push(line.into_iter().map(point).collect());
+    }
+
+    lines
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,175 @@
+use super::*;
+
+#[test]
+fn contour_plot_new() {
+    let contour = ContourPlot::new();
+    assert!(contour.keys.is_empty());
+    assert!(contour.x.is_empty());
+    assert!(contour.y.is_empty());
+    assert!(contour.z.is_empty());
+    assert!(matches!(contour.levels, Levels::Number(5)));
+    assert!(matches!(contour.method, ContourMethod::MarchingSquares));
+    assert!(contour.labels);
+    assert!(contour.number_format.is_none());
+}
+
+#[test]
+fn contour_plot_from_grid() {
+    let contour = ContourPlot::from_grid(&[0.0, 1.0], &[0.0, 1.0, 2.0], |x, y| x - y);
+    assert_eq!(contour.x, vec![0.0, 1.0]);
+    assert_eq!(contour.y, vec![0.0, 1.0, 2.0]);
+    assert_eq!(contour.z, vec![0.0, 1.0, -1.0, 0.0, -2.0, -1.0]);
+}
+
+#[test]
+fn levels_values() {
+    let z = [0.0, f64::NAN, 6.0, f64::INFINITY];
+    assert_eq!(Levels::Number(2).values(&z), vec![2.0, 4.0]);
+    assert!(Levels::Number(2).values(&[f64::NAN]).is_empty());
+    assert_eq!(Levels::Values(vec![1.0, 5.0]).values(&z), vec![1.0, 5.0]);
+}
+
+#[test]
+fn marching_squares_single_corner() {
+    // Only the bottom-left corner is above the level.
+    let lines = marching_squares(&[0.0, 1.0], &[0.0, 1.0], &[1.0, 0.0, 0.0, 0.0], 0.5);
+    assert_eq!(lines, vec![vec![(0.5, 0.0), (0.0, 0.5)]]);
+}
+
+#[test]
+fn marching_squares_no_crossing() {
+    let lines = marching_squares(&[0.0, 1.0], &[0.0, 1.0], &[1.0, 1.0, 1.0, 1.0], 0.5);
+    assert!(lines.is_empty());
+    let lines = marching_squares(&[0.0, 1.0], &[0.0, 1.0], &[1.0, 1.0, 1.0], 0.5);
+    assert!(lines.is_empty());
+}
+
+#[test]
+fn marching_squares_saddle() {
+    // Bottom-left and top-right corners are above the level.
+    let z = [1.0, 0.0, 0.0, 1.0];
+    let lines = marching_squares(&[0.0, 1.0], &[0.0, 1.0], &z, 0.25);
+    assert_eq!(
+        lines,
+        vec![
+            vec![(0.75, 0.0), (1.0, 0.25)],
+            vec![(0.0, 0.75), (0.25, 1.0)]
+        ]
+    );
+    let lines = marching_squares(&[0.0, 1.0], &[0.0, 1.0], &z, 0.75);
+    assert_eq!(
+        lines,
+        vec![
+            vec![(0.25, 0.0), (0.0, 0.25)],
+            vec![(1.0, 0.75), (0.75, 1.0)]
+        ]
+    );
+}
+
+#[test]
+fn marching_squares_joined_lines() {
+    // The level crosses two cells, which are joined into a single line.
+    let z = [0.0, 0.0, 0.0, 1.0, 1.0, 1.0];
+    let lines = marching_squares(&[0.0, 1.0, 2.0], &[0.0, 1.0], &z, 0.5);
+    assert_eq!(lines, vec![vec![(0.0, 0.5), (1.0, 0.5), (2.0, 0.5)]]);
+}
+
+#[test]
+fn marching_squares_closed_line() {
+    // A peak in the middle of a 3x3 grid.
+    let z = [0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0];
+    let lines = marching_squares(&[0.0, 1.0, 2.0], &[0.0, 1.0, 2.0], &z, 0.5);
+    assert_eq!(lines.len(), 1);
+    assert_eq!(lines[0].len(), 5);
+    assert_eq!(lines[0].first(), lines[0].last());
+}
+
+#[test]
+fn marching_squares_skip_non_finite() {
+    let z = [0.0, 0.0, f64::NAN, 1.0, 1.0, 1.0];
+    let lines = marching_squares(&[0.0, 1.0, 2.0], &[0.0, 1.0], &z, 0.5);
+    assert_eq!(lines, vec![vec![(0.0, 0.5), (1.0, 0.5)]]);
+}
+
+#[test]
+fn contour_plot_to_string() {
+    let mut contour = ContourPlot::from_grid(&[0.0, 1.0, 2.0], &[0.0, 1.0], |_, y| {
+        if y > 0.0 {
+            1.0
+        } else {
+            0.0
+        }
+    });
+    contour.levels = Levels::Values(vec![0.5]);
+    contour.labels = false;
+    assert_eq!(
+        contour.to_string(),
+        "\t\\addplot3[\n\t\tcontour prepared={labels=false},\n\t] coordinates {\n\t\t(0,0.5,0.5)\n\t\t(1,0.5,0.5)\n\t\t(2,0.5,0.5)\n\t};"
+    );
+
+    contour.levels = Levels::Values(vec![0.25, 0.75]);
+    contour.add_key(PlotKey::Custom(String::from("thick")));
+    assert_eq!(
+        contour.to_string(),
+        "\t\\addplot3[\n\t\tcontour prepared={labels=false},\n\t\tthick,\n\t] coordinates {\n\t\t(0,0.25,0.25)\n\t\t(1,0.25,0.25)\n\t\t(2,0.25,0.25)\n\n\t\t(0,0.75,0.75)\n\t\t(1,0.75,0.75)\n\t\t(2,0.75,0.75)\n\t};"
+    );
+}
+
+#[test]
+fn contour_plot_gnuplot_to_string() {
+    let mut contour = ContourPlot::from_grid(&[0.0, 1.0], &[0.0, 1.0], |x, y| x + y);
+    contour.method = ContourMethod::Gnuplot;
+    assert_eq!(
+        contour.to_string(),
+        "\t\\addplot3[\n\t\tcontour gnuplot={number=5, labels=true},\n\t\tmesh/rows=2,\n\t\tmesh/cols=2,\n\t] coordinates {\n\t\t(0,0,0)\n\t\t(1,0,1)\n\t\t(0,1,1)\n\t\t(1,1,2)\n\t};"
+    );
+}
+
+#[test]
+fn contour_plot_filled_to_string() {
+    let mut contour = ContourPlot::from_grid(&[0.0, 1.0], &[0.0, 1.0], |x, y| x + y);
+    contour.method = ContourMethod::Filled;
+    contour.levels = Levels::Values(vec![0.5, 1.5]);
+    assert_eq!(
+        contour.to_string(),
+        "\t\\addplot3[\n\t\tcontour filled={levels={0.5,1.5}},\n\t\tmesh/rows=2,\n\t\tmesh/cols=2,\n\t] coordinates {\n\t\t(0,0,0)\n\t\t(1,0,1)\n\t\t(0,1,1)\n\t\t(1,1,2)\n\t};"
+    );
+}
+
+#[test]
+fn contour_plot_invalid_grid() {
+    let mut contour = ContourPlot::from_grid(&[0.0, 1.0], &[0.0, 1.0], |x, y| x + y);
+    contour.z.pop();
+    assert_eq!(
+        contour.to_string(),
+        "\t\\addplot3[\n\t\tcontour prepared={labels=true},\n\t] coordinates {\n\t};"
+    );
+}
+
+#[test]
+fn contour_plot_write_table() {
+    let mut contour = ContourPlot::from_grid(&[0.0, 1.0], &[0.0, 1.0], |_, y| y);
+    contour.levels = Levels::Values(vec![0.25, 0.75]);
+    let mut table = Vec::new();
+    contour
+        .write_table(&mut table, NumberFormat::new())
+        .unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "x y z\n0 0.25 0.25\n1 0.25 0.25\n\n0 0.75 0.75\n1 0.75 0.75\n"
+    );
+}
+
+#[test]
+fn contour_plot_add_requirements() {
+    let mut contour = ContourPlot::new();
+    let mut preamble = Preamble::new();
+    preamble.set_compat("1.9");
+    let before = preamble.to_string();
+    contour.add_requirements(&mut preamble);
+    assert_eq!(preamble.to_string(), before);
+
+    contour.method = ContourMethod::Filled;
+    contour.add_requirements(&mut preamble);
+    assert!(preamble.to_string().contains("\\pgfplotsset{compat=1.14}"));
+}
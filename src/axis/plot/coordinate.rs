@@ -1,12 +1,33 @@
+use crate::number::NumberFormat;
 use std::fmt;
 
 // Only imported for documentation. If you notice this is no longer the case,
 // please change it.
 #[allow(unused_imports)]
-use crate::axis::plot::{Plot2D, PlotKey};
+use crate::axis::{
+    plot::{ErrorDirection, Plot2D, Plot3D, PlotKey, PointMetaSource},
+    Axis,
+};
 
 /// Coordinate in a two-dimensional plot.
-#[derive(Clone, Copy, Debug)]
+///
+/// Errors can be symmetric (e.g. [`Coordinate2D::error_x`]) or different in
+/// the positive and negative directions (e.g. [`Coordinate2D::error_x_plus`]
+/// and [`Coordinate2D::error_x_minus`]). An asymmetric error takes precedence
+/// over the symmetric error in its direction. Which errors are drawn is
+/// controlled by [`PlotKey::XErrorDirection`] and [`PlotKey::YErrorDirection`]
+/// e.g. [`ErrorDirection::Both`] draws the errors in both directions.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::plot::coordinate::Coordinate2D;
+///
+/// let mut point: Coordinate2D = (1.0, -1.0, None, Some(0.5)).into();
+/// point.error_y_plus = Some(1.0);
+/// assert_eq!(point.to_string(), "(1,-1)\t+- (0,0.5)\t+= (0,1)");
+/// ```
+#[derive(Clone, Debug)]
 #[non_exhaustive]
 pub struct Coordinate2D {
     pub x: f64,
@@ -19,25 +40,94 @@ pub struct Coordinate2D {
     /// are only drawn if both [`PlotKey::YError`] and
     /// [`PlotKey::YErrorDirection`] are set in the [`Plot2D`].
     pub error_y: Option<f64>,
-    // What to do when `point meta=explicit` in plot?
-    // Should we add an Option<point_meta> here?
-    // Is `point meta` skipped same as error when it is not set?
+    /// Error of the *x* coordinate in the positive direction. It is drawn
+    /// under the same conditions as [`Coordinate2D::error_x`].
+    pub error_x_plus: Option<f64>,
+    /// Error of the *x* coordinate in the negative direction. It is drawn
+    /// under the same conditions as [`Coordinate2D::error_x`].
+    pub error_x_minus: Option<f64>,
+    /// Error of the *y* coordinate in the positive direction. It is drawn
+    /// under the same conditions as [`Coordinate2D::error_y`].
+    pub error_y_plus: Option<f64>,
+    /// Error of the *y* coordinate in the negative direction. It is drawn
+    /// under the same conditions as [`Coordinate2D::error_y`].
+    pub error_y_minus: Option<f64>,
+    /// Additional data of the coordinate e.g. to control the color of its
+    /// marker. This is ignored unless [`PlotKey::PointMeta`] is set to
+    /// [`PointMetaSource::Explicit`] or [`PointMetaSource::ExplicitSymbolic`]
+    /// in the [`Plot2D`].
+    pub point_meta: Option<PointMeta>,
 }
 
 impl fmt::Display for Coordinate2D {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "({},{})", self.x, self.y)?;
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl Coordinate2D {
+    /// Same as [`fmt::Display`], but with a custom [`NumberFormat`].
+    pub(crate) fn fmt_with(&self, f: &mut fmt::Formatter<'_>, format: NumberFormat) -> fmt::Result {
+        write!(f, "({},{})", format.display(self.x), format.display(self.y))?;
 
         if self.error_x.is_some() || self.error_y.is_some() {
-            let error_x = self.error_x.unwrap_or(0.0);
-            let error_y = self.error_y.unwrap_or(0.0);
+            let error_x = format.display(self.error_x.unwrap_or(0.0));
+            let error_y = format.display(self.error_y.unwrap_or(0.0));
             write!(f, "\t+- ({error_x},{error_y})")?;
         }
+        // `+=` and `-=` overwrite both components of the error in their
+        // direction, so the symmetric error has to be repeated.
+        if self.error_x_plus.is_some() || self.error_y_plus.is_some() {
+            let (error_x, error_y) = self.errors_plus();
+            let (error_x, error_y) = (format.display(error_x), format.display(error_y));
+            write!(f, "\t+= ({error_x},{error_y})")?;
+        }
+        if self.error_x_minus.is_some() || self.error_y_minus.is_some() {
+            let (error_x, error_y) = self.errors_minus();
+            let (error_x, error_y) = (format.display(error_x), format.display(error_y));
+            write!(f, "\t-= ({error_x},{error_y})")?;
+        }
+        if let Some(point_meta) = &self.point_meta {
+            write!(f, "\t[")?;
+            point_meta.fmt_with(f, format)?;
+            write!(f, "]")?;
+        }
 
         Ok(())
     }
 }
 
+impl Coordinate2D {
+    /// Whether the coordinate has an error in any direction.
+    pub(crate) fn has_errors(&self) -> bool {
+        self.error_x.is_some() || self.error_y.is_some() || self.has_asymmetric_errors()
+    }
+    /// Whether the coordinate has an error only in the positive or negative
+    /// direction.
+    pub(crate) fn has_asymmetric_errors(&self) -> bool {
+        self.error_x_plus.is_some()
+            || self.error_x_minus.is_some()
+            || self.error_y_plus.is_some()
+            || self.error_y_minus.is_some()
+    }
+    /// Return the `(x, y)` errors in the positive direction. Missing errors
+    /// fall back to the symmetric error, and then to zero.
+    pub(crate) fn errors_plus(&self) -> (f64, f64) {
+        (
+            self.error_x_plus.or(self.error_x).unwrap_or(0.0),
+            self.error_y_plus.or(self.error_y).unwrap_or(0.0),
+        )
+    }
+    /// Return the `(x, y)` errors in the negative direction. Missing errors
+    /// fall back to the symmetric error, and then to zero.
+    pub(crate) fn errors_minus(&self) -> (f64, f64) {
+        (
+            self.error_x_minus.or(self.error_x).unwrap_or(0.0),
+            self.error_y_minus.or(self.error_y).unwrap_or(0.0),
+        )
+    }
+}
+
 impl From<(f64, f64)> for Coordinate2D {
     /// Conversion from an `(x,y)` tuple into a two-dimensional coordinate.
     ///
@@ -59,6 +149,11 @@ impl From<(f64, f64)> for Coordinate2D {
             y: coordinate.1,
             error_x: None,
             error_y: None,
+            error_x_plus: None,
+            error_x_minus: None,
+            error_y_plus: None,
+            error_y_minus: None,
+            point_meta: None,
         }
     }
 }
@@ -85,6 +180,137 @@ impl From<(f64, f64, Option<f64>, Option<f64>)> for Coordinate2D {
             y: coordinate.1,
             error_x: coordinate.2,
             error_y: coordinate.3,
+            error_x_plus: None,
+            error_x_minus: None,
+            error_y_plus: None,
+            error_y_minus: None,
+            point_meta: None,
+        }
+    }
+}
+
+impl From<(f64, f64, PointMeta)> for Coordinate2D {
+    /// Conversion from an `(x,y,point_meta)` tuple into a two-dimensional
+    /// coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::{Coordinate2D, PointMeta};
+    ///
+    /// let point: Coordinate2D = (1.0, -1.0, PointMeta::Numeric(0.5)).into();
+    ///
+    /// assert_eq!(point.x, 1.0);
+    /// assert_eq!(point.y, -1.0);
+    /// assert!(point.error_x.is_none());
+    /// assert!(point.error_y.is_none());
+    /// assert!(matches!(point.point_meta, Some(PointMeta::Numeric(_))));
+    /// ```
+    fn from(coordinate: (f64, f64, PointMeta)) -> Self {
+        Coordinate2D {
+            x: coordinate.0,
+            y: coordinate.1,
+            error_x: None,
+            error_y: None,
+            error_x_plus: None,
+            error_x_minus: None,
+            error_y_plus: None,
+            error_y_minus: None,
+            point_meta: Some(coordinate.2),
+        }
+    }
+}
+
+/// Coordinate in a three-dimensional plot.
+///
+/// Coordinates of a [`Plot3D`] with a mesh (e.g. a surface) have to be sorted
+/// in scanlines as explained in [`Plot3D::from_grid`].
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub struct Coordinate3D {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+    /// Additional data of the coordinate e.g. to control the color of its
+    /// marker. This is ignored unless [`PlotKey::PointMeta`] is set to
+    /// [`PointMetaSource::Explicit`] or [`PointMetaSource::ExplicitSymbolic`]
+    /// in the [`Plot3D`].
+    pub point_meta: Option<PointMeta>,
+}
+
+impl fmt::Display for Coordinate3D {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl Coordinate3D {
+    /// Same as [`fmt::Display`], but with a custom [`NumberFormat`].
+    pub(crate) fn fmt_with(&self, f: &mut fmt::Formatter<'_>, format: NumberFormat) -> fmt::Result {
+        write!(
+            f,
+            "({},{},{})",
+            format.display(self.x),
+            format.display(self.y),
+            format.display(self.z)
+        )?;
+        if let Some(point_meta) = &self.point_meta {
+            write!(f, "\t[")?;
+            point_meta.fmt_with(f, format)?;
+            write!(f, "]")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl From<(f64, f64, f64)> for Coordinate3D {
+    /// Conversion from an `(x,y,z)` tuple into a three-dimensional coordinate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::coordinate::Coordinate3D;
+    ///
+    /// let point: Coordinate3D = (1.0, -1.0, 2.0).into();
+    ///
+    /// assert_eq!(point.x, 1.0);
+    /// assert_eq!(point.y, -1.0);
+    /// assert_eq!(point.z, 2.0);
+    /// ```
+    fn from(coordinate: (f64, f64, f64)) -> Self {
+        Coordinate3D {
+            x: coordinate.0,
+            y: coordinate.1,
+            z: coordinate.2,
+            point_meta: None,
+        }
+    }
+}
+
+/// Point meta data of a coordinate.
+#[derive(Clone, Debug)]
+pub enum PointMeta {
+    /// Numeric value e.g. mapped into the colormap of the [`Axis`].
+    Numeric(f64),
+    /// Symbolic value e.g. the name of a scatter class. This is written
+    /// verbatim, and it is enclosed in braces in data tables, so it can
+    /// contain white space but its own braces have to be balanced.
+    Symbolic(String),
+}
+
+impl fmt::Display for PointMeta {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl PointMeta {
+    /// Same as [`fmt::Display`], but with a custom [`NumberFormat`].
+    pub(crate) fn fmt_with(&self, f: &mut fmt::Formatter<'_>, format: NumberFormat) -> fmt::Result {
+        match self {
+            PointMeta::Numeric(value) => write!(f, "{}", format.display(*value)),
+            PointMeta::Symbolic(value) => write!(f, "{value}"),
         }
     }
 }
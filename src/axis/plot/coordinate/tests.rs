@@ -7,6 +7,7 @@ fn coordinate_2d_from_short_tuple() {
     assert_eq!(coord.y, -1.0);
     assert!(coord.error_x.is_none());
     assert!(coord.error_y.is_none());
+    assert!(coord.point_meta.is_none());
 }
 
 #[test]
@@ -50,3 +51,92 @@ fn coordinate_2d_to_string() {
     let coord: Coordinate2D = (1.0, -1.0, Some(4.0), Some(3.0)).into();
     assert_eq!(coord.to_string(), "(1,-1)\t+- (4,3)");
 }
+
+#[test]
+fn coordinate_2d_asymmetric_errors_to_string() {
+    let mut coord: Coordinate2D = (1.0, -1.0).into();
+    assert!(coord.error_x_plus.is_none());
+    assert!(coord.error_x_minus.is_none());
+    assert!(coord.error_y_plus.is_none());
+    assert!(coord.error_y_minus.is_none());
+
+    coord.error_y_plus = Some(2.0);
+    assert_eq!(coord.to_string(), "(1,-1)\t+= (0,2)");
+
+    coord.error_y_minus = Some(0.5);
+    assert_eq!(coord.to_string(), "(1,-1)\t+= (0,2)\t-= (0,0.5)");
+
+    coord.error_y_plus = None;
+    coord.error_x_minus = Some(3.0);
+    assert_eq!(coord.to_string(), "(1,-1)\t-= (3,0.5)");
+
+    coord.error_x = Some(4.0);
+    coord.error_y = Some(1.0);
+    coord.error_x_plus = Some(5.0);
+    coord.point_meta = Some(PointMeta::Numeric(7.0));
+    assert_eq!(
+        coord.to_string(),
+        "(1,-1)\t+- (4,1)\t+= (5,1)\t-= (3,0.5)\t[7]"
+    );
+}
+
+#[test]
+fn coordinate_2d_errors_plus_minus() {
+    let mut coord: Coordinate2D = (1.0, -1.0, Some(4.0), None).into();
+    assert!(coord.has_errors());
+    assert!(!coord.has_asymmetric_errors());
+    assert_eq!(coord.errors_plus(), (4.0, 0.0));
+    assert_eq!(coord.errors_minus(), (4.0, 0.0));
+
+    coord.error_x = None;
+    coord.error_y_minus = Some(2.0);
+    assert!(coord.has_errors());
+    assert!(coord.has_asymmetric_errors());
+    assert_eq!(coord.errors_plus(), (0.0, 0.0));
+    assert_eq!(coord.errors_minus(), (0.0, 2.0));
+}
+
+#[test]
+fn coordinate_3d_from_tuple() {
+    let coord: Coordinate3D = (1.0, -1.0, 2.5).into();
+    assert_eq!(coord.x, 1.0);
+    assert_eq!(coord.y, -1.0);
+    assert_eq!(coord.z, 2.5);
+    assert!(coord.point_meta.is_none());
+}
+
+#[test]
+fn coordinate_3d_to_string() {
+    let coord: Coordinate3D = (1.0, -1.0, 2.5).into();
+    assert_eq!(coord.to_string(), "(1,-1,2.5)");
+
+    let coord: Coordinate3D = (0.0, f64::NAN, f64::INFINITY).into();
+    assert_eq!(coord.to_string(), "(0,nan,inf)");
+}
+
+#[test]
+fn coordinate_2d_from_point_meta_tuple() {
+    let coord: Coordinate2D = (1.0, -1.0, PointMeta::Numeric(2.0)).into();
+    assert_eq!(coord.x, 1.0);
+    assert_eq!(coord.y, -1.0);
+    assert!(coord.error_x.is_none());
+    assert!(coord.error_y.is_none());
+    assert!(matches!(coord.point_meta, Some(PointMeta::Numeric(_))));
+}
+
+#[test]
+fn coordinate_2d_point_meta_to_string() {
+    let mut coord: Coordinate2D = (1.0, -1.0, PointMeta::Numeric(2.5)).into();
+    assert_eq!(coord.to_string(), "(1,-1)\t[2.5]");
+
+    coord.error_x = Some(3.0);
+    coord.point_meta = Some(PointMeta::Symbolic(String::from("class a")));
+    assert_eq!(coord.to_string(), "(1,-1)\t+- (3,0)\t[class a]");
+}
+
+#[test]
+fn point_meta_to_string() {
+    assert_eq!(PointMeta::Numeric(-0.5).to_string(), "-0.5");
+    assert_eq!(PointMeta::Numeric(f64::NAN).to_string(), "nan");
+    assert_eq!(PointMeta::Symbolic(String::from("a")).to_string(), "a");
+}
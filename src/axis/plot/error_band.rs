@@ -0,0 +1,258 @@
+use crate::axis::plot::{add_key, fmt_addplot, PlotKey};
+use crate::{number::NumberFormat, preamble::Preamble};
+use std::{fmt, io};
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{axis::Axis, Picture};
+
+/// Line with a shaded band around it inside an [`Axis`] e.g. the mean and
+/// standard deviation of several runs.
+///
+/// The band is filled between the `lower` and `upper` bounds, and the line
+/// goes through the `y` values. If `x`, `y`, `lower` and `upper` do not all
+/// have the same length, nothing is plotted.
+///
+/// Adding an [`ErrorBand`] to an [`Axis`] environment is equivalent to:
+///
+/// ```text
+/// \addplot+[fill, draw=none, mark=none, fill opacity=0.3, forget plot]
+///     % band;
+/// \addplot+[PlotKeys]
+///     % line;
+/// ```
+///
+/// The band is a forgotten plot, so it does not advance the cycle list: the
+/// band and the line have the same color, and only the line has a legend
+/// entry (see [`ErrorBand::legend`]).
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{plot::error_band::ErrorBand, Axis};
+///
+/// let epochs: Vec<f64> = (1..=10).map(f64::from).collect();
+/// let loss: Vec<f64> = epochs.iter().map(|epoch| 1.0 / epoch).collect();
+/// let deviation = vec![0.05; 10];
+///
+/// let mut axis = Axis::new();
+/// axis.plots.push(ErrorBand::from_deviation(epochs, loss, &deviation).into());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ErrorBand {
+    keys: Vec<PlotKey>,
+    /// *x* coordinates of the line and the band.
+    pub x: Vec<f64>,
+    /// *y* coordinates of the line.
+    pub y: Vec<f64>,
+    /// Lower bound of the band at each *x* coordinate.
+    pub lower: Vec<f64>,
+    /// Upper bound of the band at each *x* coordinate.
+    pub upper: Vec<f64>,
+    /// Opacity of the band, between `0.0` (transparent) and `1.0` (opaque).
+    /// Defaults to `0.3`.
+    pub opacity: f64,
+    /// Format of the numbers in the coordinates. If [`None`], the
+    /// [`Picture::number_format`] is used (or the default format if the plot is
+    /// not inside a [`Picture`]).
+    pub number_format: Option<NumberFormat>,
+    /// Entry of the line in the legend of the [`Axis`], written with
+    /// `\addlegendentry` right after the plot. Braces in the entry have to be
+    /// balanced. The entry is not written if the [`Axis::legend_order`] is
+    /// set.
+    pub legend: Option<String>,
+}
+
+impl Default for ErrorBand {
+    fn default() -> Self {
+        ErrorBand {
+            keys: Vec::new(),
+            x: Vec::new(),
+            y: Vec::new(),
+            lower: Vec::new(),
+            upper: Vec::new(),
+            opacity: 0.3,
+            number_format: None,
+            legend: None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorBand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl ErrorBand {
+    /// Same as [`fmt::Display`], but `number_format` is used for the
+    /// coordinates unless the plot has its own [`ErrorBand::number_format`].
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        let number_format = self.number_format.unwrap_or(number_format);
+        let fmt_coordinates = |f: &mut fmt::Formatter<'_>, coordinates: Vec<(f64, f64)>| {
+            writeln!(f, " coordinates {{")?;
+            for (x, y) in coordinates {
+                let (x, y) = (number_format.display(x), number_format.display(y));
+                writeln!(f, "\t\t({x},{y})")?;
+            }
+            write!(f, "\t}};")
+        };
+
+        fmt_addplot(f, "addplot+", &self.band_keys())?;
+        fmt_coordinates(f, self.band())?;
+        writeln!(f)?;
+        fmt_addplot(f, "addplot+", &self.keys)?;
+        fmt_coordinates(f, self.line())
+    }
+}
+
+impl ErrorBand {
+    /// Creates a new, empty error band.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::error_band::ErrorBand;
+    ///
+    /// let mut band = ErrorBand::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates an error band from the `lower` and `upper` bounds of the band
+    /// around the line through `x` and `y`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::error_band::ErrorBand;
+    ///
+    /// let band = ErrorBand::from_bounds(
+    ///     vec![0.0, 1.0],
+    ///     vec![1.0, 2.0],
+    ///     vec![0.5, 1.0],
+    ///     vec![1.5, 2.5],
+    /// );
+    /// assert_eq!(band.upper, vec![1.5, 2.5]);
+    /// ```
+    pub fn from_bounds(x: Vec<f64>, y: Vec<f64>, lower: Vec<f64>, upper: Vec<f64>) -> Self {
+        ErrorBand {
+            x,
+            y,
+            lower,
+            upper,
+            ..Default::default()
+        }
+    }
+    /// Creates an error band spanning `deviation` below and above the line
+    /// through `x` and `y` e.g. the mean plus and minus one standard deviation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::error_band::ErrorBand;
+    ///
+    /// let band = ErrorBand::from_deviation(vec![0.0, 1.0], vec![1.0, 2.0], &[0.5, 0.25]);
+    /// assert_eq!(band.lower, vec![0.5, 1.75]);
+    /// assert_eq!(band.upper, vec![1.5, 2.25]);
+    /// ```
+    pub fn from_deviation(x: Vec<f64>, y: Vec<f64>, deviation: &[f64]) -> Self {
+        let lower = y.iter().zip(deviation).map(|(y, d)| y - d).collect();
+        let upper = y.iter().zip(deviation).map(|(y, d)| y + d).collect();
+
+        ErrorBand::from_bounds(x, y, lower, upper)
+    }
+    /// Add a key to control the appearance of the line. This will overwrite
+    /// any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{error_band::ErrorBand, PlotKey};
+    ///
+    /// let mut band = ErrorBand::new();
+    /// band.add_key(PlotKey::Custom(String::from("mark=none")));
+    /// ```
+    pub fn add_key(&mut self, key: PlotKey) {
+        add_key(&mut self.keys, key);
+    }
+    /// Whether all the series have the same length.
+    fn is_valid(&self) -> bool {
+        let length = self.x.len();
+        self.y.len() == length && self.lower.len() == length && self.upper.len() == length
+    }
+    /// Return the `(x, y)` coordinates of the line.
+    fn line(&self) -> Vec<(f64, f64)> {
+        if !self.is_valid() {
+            return Vec::new();
+        }
+        self.x.iter().copied().zip(self.y.iter().copied()).collect()
+    }
+    /// Return the `(x, y)` coordinates of the outline of the band: along the
+    /// lower bound and back along the upper bound.
+    fn band(&self) -> Vec<(f64, f64)> {
+        if !self.is_valid() {
+            return Vec::new();
+        }
+        let lower = self.x.iter().copied().zip(self.lower.iter().copied());
+        let upper = self.x.iter().copied().zip(self.upper.iter().copied());
+
+        lower.chain(upper.rev()).collect()
+    }
+    /// Return the keys written in the options of the `\addplot` command of the
+    /// band.
+    fn band_keys(&self) -> Vec<PlotKey> {
+        vec![
+            PlotKey::Custom(String::from("fill")),
+            PlotKey::Custom(String::from("draw=none")),
+            PlotKey::Custom(String::from("mark=none")),
+            PlotKey::Custom(format!("fill opacity={}", self.opacity)),
+            PlotKey::ForgetPlot,
+        ]
+    }
+    /// Write the plot such that the coordinates are read from the data file at
+    /// `path` (as written by [`ErrorBand::write_table`]) instead of being
+    /// inlined.
+    pub(crate) fn fmt_table(&self, f: &mut fmt::Formatter<'_>, path: &str) -> fmt::Result {
+        fmt_addplot(f, "addplot+", &self.band_keys())?;
+        writeln!(f, " table[x=bx, y=by] {{{path}}};")?;
+        fmt_addplot(f, "addplot+", &self.keys)?;
+        write!(f, " table[x=x, y=y] {{{path}}};")
+    }
+    /// Write the coordinates as a whitespace separated table with a header
+    /// row. The `x` and `y` columns are the line, and the `bx` and `by`
+    /// columns are the outline of the band. The band has twice as many
+    /// coordinates as the line, so the line is padded with `nan`, which
+    /// PGFPlots discards.
+    pub(crate) fn write_table<W: io::Write>(
+        &self,
+        mut writer: W,
+        number_format: NumberFormat,
+    ) -> io::Result<()> {
+        let number_format = self.number_format.unwrap_or(number_format);
+        writeln!(writer, "x y bx by")?;
+
+        let line = self.line();
+        for (i, (band_x, band_y)) in self.band().into_iter().enumerate() {
+            let (x, y) = line.get(i).copied().unwrap_or((f64::NAN, f64::NAN));
+            let row = [x, y, band_x, band_y].map(|value| number_format.display(value).to_string());
+            writeln!(writer, "{}", row.join(" "))?;
+        }
+
+        writer.flush()
+    }
+    /// Add the packages and libraries needed by the plot to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        for key in self.keys.iter() {
+            key.add_requirements(preamble);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,62 @@
+use super::*;
+
+#[test]
+fn error_band_new() {
+    let band = ErrorBand::new();
+    assert!(band.keys.is_empty());
+    assert!(band.x.is_empty());
+    assert!(band.y.is_empty());
+    assert!(band.lower.is_empty());
+    assert!(band.upper.is_empty());
+    assert_eq!(band.opacity, 0.3);
+    assert!(band.number_format.is_none());
+    assert!(band.legend.is_none());
+}
+
+#[test]
+fn error_band_from_deviation() {
+    let band = ErrorBand::from_deviation(vec![0.0, 1.0], vec![1.0, -1.0], &[0.5, 2.0]);
+    assert_eq!(band.x, vec![0.0, 1.0]);
+    assert_eq!(band.y, vec![1.0, -1.0]);
+    assert_eq!(band.lower, vec![0.5, -3.0]);
+    assert_eq!(band.upper, vec![1.5, 1.0]);
+}
+
+#[test]
+fn error_band_to_string() {
+    let mut band = ErrorBand::from_bounds(
+        vec![0.0, 1.0],
+        vec![1.0, 2.0],
+        vec![0.5, 1.5],
+        vec![2.0, 3.0],
+    );
+    band.opacity = 0.5;
+    band.add_key(PlotKey::Custom(String::from("thick")));
+    assert_eq!(
+        band.to_string(),
+        "\t\\addplot+[\n\t\tfill,\n\t\tdraw=none,\n\t\tmark=none,\n\t\tfill opacity=0.5,\n\t\tforget plot,\n\t] coordinates {\n\t\t(0,0.5)\n\t\t(1,1.5)\n\t\t(1,3)\n\t\t(0,2)\n\t};\n\t\\addplot+[\n\t\tthick,\n\t] coordinates {\n\t\t(0,1)\n\t\t(1,2)\n\t};"
+    );
+}
+
+#[test]
+fn error_band_invalid() {
+    let band = ErrorBand::from_bounds(vec![0.0, 1.0], vec![1.0], vec![0.5, 1.5], vec![2.0, 3.0]);
+    assert!(band.line().is_empty());
+    assert!(band.band().is_empty());
+}
+
+#[test]
+fn error_band_write_table() {
+    let band = ErrorBand::from_bounds(
+        vec![0.0, 1.0],
+        vec![1.0, 2.0],
+        vec![0.5, 1.5],
+        vec![2.0, 3.0],
+    );
+    let mut table = Vec::new();
+    band.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "x y bx by\n0 1 0 0.5\n1 2 1 1.5\nnan nan 1 3\nnan nan 0 2\n"
+    );
+}
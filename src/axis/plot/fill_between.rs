@@ -0,0 +1,128 @@
+use crate::axis::plot::{add_key, fmt_addplot, PlotKey};
+use crate::{number::NumberFormat, preamble::Preamble};
+use std::fmt;
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{
+    axis::{plot::Plot2D, Axis},
+    Picture,
+};
+
+/// Filled area between two named paths inside an [`Axis`].
+///
+/// The paths are named with [`PlotKey::NamePath`] on the plots that draw them,
+/// which have to come before the [`FillBetween`] in the same [`Axis`]. This is
+/// done automatically by [`Axis::fill_between`].
+///
+/// Adding a [`FillBetween`] to an [`Axis`] environment is equivalent to:
+///
+/// ```text
+/// \addplot[PlotKeys] fill between[of=first and second];
+/// ```
+///
+/// This requires the `fillbetween` PGFPlots library, which is added to the
+/// [`Picture::preamble`] automatically.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{
+///     plot::{fill_between::FillBetween, Plot2D, PlotKey},
+///     Axis,
+/// };
+///
+/// let mut lower = Plot2D::new();
+/// lower.coordinates = vec![(0.0, 0.0).into(), (1.0, 1.0).into()];
+/// lower.add_key(PlotKey::NamePath(String::from("lower")));
+/// let mut upper = Plot2D::new();
+/// upper.coordinates = vec![(0.0, 1.0).into(), (1.0, 3.0).into()];
+/// upper.add_key(PlotKey::NamePath(String::from("upper")));
+///
+/// let mut axis = Axis::new();
+/// axis.plots.push(lower.into());
+/// axis.plots.push(upper.into());
+/// axis.plots.push(FillBetween::new("lower", "upper").into());
+/// ```
+#[derive(Clone, Debug)]
+pub struct FillBetween {
+    keys: Vec<PlotKey>,
+    /// Name of the first path.
+    pub first: String,
+    /// Name of the second path.
+    pub second: String,
+    /// Restrict the filled area to the `(min, max)` domain of the *x*
+    /// coordinates. If [`None`], the area is filled wherever both paths are
+    /// defined.
+    pub soft_clip: Option<(f64, f64)>,
+}
+
+impl fmt::Display for FillBetween {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl FillBetween {
+    /// Same as [`fmt::Display`], but `number_format` is used for the soft clip
+    /// domain.
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        fmt_addplot(f, "addplot", &self.keys)?;
+        write!(f, " fill between[of={} and {}", self.first, self.second)?;
+        if let Some((min, max)) = self.soft_clip {
+            let (min, max) = (number_format.display(min), number_format.display(max));
+            write!(f, ", soft clip={{domain={min}:{max}}}")?;
+        }
+        write!(f, "];")
+    }
+}
+
+impl FillBetween {
+    /// Creates a new fill between the paths named `first` and `second`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::fill_between::FillBetween;
+    ///
+    /// let fill = FillBetween::new("lower", "upper");
+    /// assert_eq!(fill.first, "lower");
+    /// ```
+    pub fn new<S: Into<String>, T: Into<String>>(first: S, second: T) -> Self {
+        FillBetween {
+            keys: Vec::new(),
+            first: first.into(),
+            second: second.into(),
+            soft_clip: None,
+        }
+    }
+    /// Add a key to control the appearance of the filled area. This will
+    /// overwrite any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{fill_between::FillBetween, PlotKey};
+    ///
+    /// let mut fill = FillBetween::new("lower", "upper");
+    /// fill.add_key(PlotKey::Custom(String::from("fill=gray, opacity=0.5")));
+    /// ```
+    pub fn add_key(&mut self, key: PlotKey) {
+        add_key(&mut self.keys, key);
+    }
+    /// Add the packages and libraries needed by the plot to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        preamble.add_pgfplots_library("fillbetween");
+        for key in self.keys.iter() {
+            key.add_requirements(preamble);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,35 @@
+use super::*;
+
+#[test]
+fn fill_between_new() {
+    let fill = FillBetween::new("lower", String::from("upper"));
+    assert!(fill.keys.is_empty());
+    assert_eq!(fill.first, "lower");
+    assert_eq!(fill.second, "upper");
+    assert!(fill.soft_clip.is_none());
+}
+
+#[test]
+fn fill_between_to_string() {
+    let mut fill = FillBetween::new("lower", "upper");
+    assert_eq!(
+        fill.to_string(),
+        "\t\\addplot[] fill between[of=lower and upper];"
+    );
+
+    fill.add_key(PlotKey::Custom(String::from("fill=gray")));
+    fill.soft_clip = Some((-1.0, 0.5));
+    assert_eq!(
+        fill.to_string(),
+        "\t\\addplot[\n\t\tfill=gray,\n\t] fill between[of=lower and upper, soft clip={domain=-1:0.5}];"
+    );
+}
+
+#[test]
+fn fill_between_add_requirements() {
+    let mut preamble = Preamble::new();
+    FillBetween::new("lower", "upper").add_requirements(&mut preamble);
+    assert!(preamble
+        .to_string()
+        .contains("\\usepgfplotslibrary{fillbetween}\n"));
+}
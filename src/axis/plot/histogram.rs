@@ -0,0 +1,344 @@
+use crate::axis::plot::{quantile, Plot2D, PlotKey, Type2D};
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::axis::Axis;
+
+/// Histogram of one-dimensional samples.
+///
+/// A [`Histogram`] is not a plot by itself; it bins the samples and builds a
+/// [`Plot2D`] with [`Histogram::to_plot`]. Non-finite samples (and their
+/// weights) are ignored.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{
+///     plot::histogram::{Bins, Histogram, Normalization},
+///     Axis,
+/// };
+///
+/// let samples = vec![1.0, 2.0, 2.5, 3.0, 3.5, 3.8, 4.0, 5.5];
+/// let mut histogram = Histogram::from_samples(samples);
+/// histogram.bins = Bins::Count(3);
+/// histogram.normalization = Normalization::Density;
+///
+/// let mut axis = Axis::new();
+/// axis.plots.push(histogram.to_plot().into());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Histogram {
+    /// Samples to bin.
+    pub samples: Vec<f64>,
+    /// Weight of each sample. If [`None`], every sample has a weight of one.
+    /// Otherwise, there has to be one weight per sample; if not, nothing is
+    /// plotted.
+    pub weights: Option<Vec<f64>>,
+    /// How the samples are binned. Defaults to [`Bins::Sturges`].
+    pub bins: Bins,
+    /// How the bin values are normalized. Defaults to [`Normalization::Count`].
+    pub normalization: Normalization,
+    /// How the bins are drawn. Defaults to [`HistogramStyle::Bars`].
+    pub style: HistogramStyle,
+}
+
+impl Histogram {
+    /// Creates a new, empty histogram.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::histogram::Histogram;
+    ///
+    /// let mut histogram = Histogram::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates a histogram of `samples` with the default binning.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::histogram::Histogram;
+    ///
+    /// let histogram = Histogram::from_samples(vec![1.0, 2.0, 2.0, 3.0]);
+    /// assert_eq!(histogram.samples.len(), 4);
+    /// ```
+    pub fn from_samples(samples: Vec<f64>) -> Self {
+        Histogram {
+            samples,
+            ..Default::default()
+        }
+    }
+    /// Return the edges of the bins in increasing order. There is one more
+    /// edge than bins. If no bin can be computed, the edges are empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::histogram::{Bins, Histogram};
+    ///
+    /// let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 4.0]);
+    /// histogram.bins = Bins::Count(2);
+    /// assert_eq!(histogram.edges(), vec![0.0, 2.0, 4.0]);
+    /// ```
+    pub fn edges(&self) -> Vec<f64> {
+        match self.data() {
+            Some(data) => {
+                let samples: Vec<f64> = data.iter().map(|&(sample, _)| sample).collect();
+                self.bins.edges(&samples)
+            }
+            None => Vec::new(),
+        }
+    }
+    /// Return the normalized value of every bin. Samples outside of the bin
+    /// edges are ignored. The last bin includes its right edge.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::histogram::{Bins, Histogram};
+    ///
+    /// let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 4.0]);
+    /// histogram.bins = Bins::Count(2);
+    /// assert_eq!(histogram.values(), vec![2.0, 1.0]);
+    /// ```
+    pub fn values(&self) -> Vec<f64> {
+        let edges = self.edges();
+        let Some(data) = self.data() else {
+            return Vec::new();
+        };
+        if edges.len() < 2 {
+            return Vec::new();
+        }
+        let (first, last) = (edges[0], edges[edges.len() - 1]);
+
+        let mut counts = vec![0.0; edges.len() - 1];
+        for (sample, weight) in data {
+            if sample < first || sample > last {
+                continue;
+            }
+            // The last bin is closed, all others are half-open.
+            let bin = edges
+                .partition_point(|&edge| edge <= sample)
+                .min(counts.len())
+                - 1;
+            counts[bin] += weight;
+        }
+
+        self.normalization.apply(&counts, &edges)
+    }
+    /// Return a [`Plot2D`] drawing the histogram. The plot has one coordinate
+    /// at the left edge of every bin, plus one at the right edge of the last
+    /// bin that repeats the last value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{histogram::{Bins, Histogram}, PlotKey};
+    ///
+    /// let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 4.0]);
+    /// histogram.bins = Bins::Count(2);
+    ///
+    /// let mut plot = histogram.to_plot();
+    /// plot.add_key(PlotKey::Custom(String::from("fill=gray")));
+    /// assert_eq!(plot.coordinates.len(), 3);
+    /// ```
+    pub fn to_plot(&self) -> Plot2D {
+        let mut plot = Plot2D::new();
+        plot.add_key(PlotKey::Type2D(match self.style {
+            HistogramStyle::Bars => Type2D::YBarInterval,
+            HistogramStyle::Steps => Type2D::ConstLeft,
+        }));
+
+        let values = self.values();
+        if let Some(&last) = values.last() {
+            plot.coordinates = self
+                .edges()
+                .into_iter()
+                .zip(values.into_iter().chain([last]))
+                .map(|coordinate| coordinate.into())
+                .collect();
+        }
+
+        plot
+    }
+    /// Return the finite `(sample, weight)` pairs, or [`None`] if the number
+    /// of weights does not match the number of samples.
+    fn data(&self) -> Option<Vec<(f64, f64)>> {
+        let weights = match &self.weights {
+            Some(weights) if weights.len() != self.samples.len() => return None,
+            Some(weights) => weights.clone(),
+            None => vec![1.0; self.samples.len()],
+        };
+
+        Some(
+            self.samples
+                .iter()
+                .copied()
+                .zip(weights)
+                .filter(|(sample, weight)| sample.is_finite() && weight.is_finite())
+                .collect(),
+        )
+    }
+}
+
+/// Control how the samples of a [`Histogram`] are binned.
+///
+/// Except for [`Bins::Edges`], the bins have equal width and span from the
+/// smallest to the largest sample. If all samples are equal, the bins span a
+/// unit interval centered on them.
+#[derive(Clone, Debug, Default)]
+#[non_exhaustive]
+pub enum Bins {
+    /// Fixed number of bins.
+    Count(usize),
+    /// Explicit bin edges. These have to be finite and strictly increasing,
+    /// and there have to be at least two of them; otherwise nothing is
+    /// plotted.
+    Edges(Vec<f64>),
+    /// Number of bins given by the Freedman–Diaconis rule i.e. the bin width
+    /// is `2 IQR / n^(1/3)`, where `IQR` is the interquartile range of the `n`
+    /// samples. Falls back to [`Bins::Sturges`] if the interquartile range is
+    /// zero, or if the rule gives more bins than samples (e.g. because of
+    /// extreme outliers).
+    FreedmanDiaconis,
+    /// Number of bins given by Sturges' rule i.e. `ceil(log2(n)) + 1` for `n`
+    /// samples.
+    #[default]
+    Sturges,
+}
+
+impl Bins {
+    /// Return the edges of the bins for the finite `samples`.
+    fn edges(&self, samples: &[f64]) -> Vec<f64> {
+        if let Bins::Edges(edges) = self {
+            let valid = edges.len() >= 2
+                && edges.iter().all(|edge| edge.is_finite())
+                && edges.windows(2).all(|pair| pair[0] < pair[1]);
+            return if valid { edges.clone() } else { Vec::new() };
+        }
+        if samples.is_empty() {
+            return Vec::new();
+        }
+
+        let (mut min, mut max) = samples
+            .iter()
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &sample| {
+                (min.min(sample), max.max(sample))
+            });
+        if min == max {
+            min -= 0.5;
+            max += 0.5;
+        }
+        let count = match self {
+            Bins::Count(count) => *count,
+            Bins::FreedmanDiaconis => {
+                let width = 2.0 * interquartile_range(samples) / (samples.len() as f64).cbrt();
+                let count = ((max - min) / width).ceil();
+                // Also rejects a zero width, and the infinite count of a
+                // subnormal one.
+                if count <= samples.len() as f64 {
+                    count.max(1.0) as usize
+                } else {
+                    sturges(samples.len())
+                }
+            }
+            Bins::Edges(_) | Bins::Sturges => sturges(samples.len()),
+        };
+        if count == 0 {
+            return Vec::new();
+        }
+
+        // Compute every edge from the minimum (instead of accumulating the
+        // width) and set the last one exactly to the maximum, such that
+        // rounding errors cannot leave the largest sample out.
+        (0..=count)
+            .map(|i| match i {
+                i if i == count => max,
+                i => min + (max - min) * i as f64 / count as f64,
+            })
+            .collect()
+    }
+}
+
+/// Number of bins given by Sturges' rule for `n` samples.
+fn sturges(n: usize) -> usize {
+    (n as f64).log2().ceil() as usize + 1
+}
+
+/// Interquartile range of the finite `samples`, with quartiles linearly
+/// interpolated between the closest ranks.
+fn interquartile_range(samples: &[f64]) -> f64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_by(f64::total_cmp);
+
+    quantile(&sorted, 0.75) - quantile(&sorted, 0.25)
+}
+
+/// Control the normalization of the bin values of a [`Histogram`].
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum Normalization {
+    /// Number of samples (or sum of their weights) in each bin.
+    #[default]
+    Count,
+    /// Fraction of the samples in each bin. The values add up to one.
+    Probability,
+    /// Fraction of the samples in each bin divided by the bin width. The area
+    /// of the histogram is one.
+    Density,
+    /// Number of samples in each bin and all bins to its left.
+    Cumulative,
+    /// Fraction of the samples in each bin and all bins to its left. The value
+    /// of the last bin is one.
+    CumulativeProbability,
+}
+
+impl Normalization {
+    /// Normalize the `counts` of the bins delimited by `edges`.
+    fn apply(&self, counts: &[f64], edges: &[f64]) -> Vec<f64> {
+        let total: f64 = counts.iter().sum();
+        // Avoid dividing by zero if no sample falls in any bin.
+        let fraction = |count: f64| if total == 0.0 { 0.0 } else { count / total };
+        let cumulative = |counts: Vec<f64>| {
+            counts
+                .into_iter()
+                .scan(0.0, |sum, count| {
+                    *sum += count;
+                    Some(*sum)
+                })
+                .collect()
+        };
+
+        match self {
+            Normalization::Count => counts.to_vec(),
+            Normalization::Probability => counts.iter().map(|&count| fraction(count)).collect(),
+            Normalization::Density => counts
+                .iter()
+                .zip(edges.windows(2))
+                .map(|(&count, edges)| fraction(count) / (edges[1] - edges[0]))
+                .collect(),
+            Normalization::Cumulative => cumulative(counts.to_vec()),
+            Normalization::CumulativeProbability => {
+                cumulative(counts.iter().map(|&count| fraction(count)).collect())
+            }
+        }
+    }
+}
+
+/// Control how the bins of a [`Histogram`] are drawn.
+#[derive(Clone, Copy, Debug, Default)]
+#[non_exhaustive]
+pub enum HistogramStyle {
+    /// Draw a bar for every bin ([`Type2D::YBarInterval`]).
+    #[default]
+    Bars,
+    /// Draw the outline of the bins as steps ([`Type2D::ConstLeft`]).
+    Steps,
+}
+
+#[cfg(test)]
+mod tests;
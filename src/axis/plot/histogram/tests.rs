@@ -0,0 +1,221 @@
+use super::*;
+
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn bins_tested() {
+    let bins = Bins::Sturges;
+    match bins {
+        Bins::Count(_) => (),
+        Bins::Edges(_) => (),
+        Bins::FreedmanDiaconis => (),
+        Bins::Sturges => (),
+    }
+}
+
+#[test]
+fn normalizations_tested() {
+    let normalization = Normalization::Count;
+    match normalization {
+        Normalization::Count => (),
+        Normalization::Probability => (),
+        Normalization::Density => (),
+        Normalization::Cumulative => (),
+        Normalization::CumulativeProbability => (),
+    }
+}
+
+#[test]
+fn histogram_styles_tested() {
+    let style = HistogramStyle::Bars;
+    match style {
+        HistogramStyle::Bars => (),
+        HistogramStyle::Steps => (),
+    }
+}
+
+#[test]
+fn histogram_new() {
+    let histogram = Histogram::new();
+    assert!(histogram.samples.is_empty());
+    assert!(histogram.weights.is_none());
+    assert!(matches!(histogram.bins, Bins::Sturges));
+    assert!(matches!(histogram.normalization, Normalization::Count));
+    assert!(matches!(histogram.style, HistogramStyle::Bars));
+    assert!(histogram.edges().is_empty());
+    assert!(histogram.values().is_empty());
+}
+
+#[test]
+fn bins_count() {
+    let mut histogram = Histogram::from_samples(vec![1.0, 2.0, 2.5, 4.0, 5.0]);
+    histogram.bins = Bins::Count(4);
+    assert_eq!(histogram.edges(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    assert_eq!(histogram.values(), vec![1.0, 2.0, 0.0, 2.0]);
+
+    histogram.bins = Bins::Count(0);
+    assert!(histogram.edges().is_empty());
+    assert!(histogram.values().is_empty());
+}
+
+#[test]
+fn bins_count_equal_samples() {
+    let mut histogram = Histogram::from_samples(vec![3.0, 3.0]);
+    histogram.bins = Bins::Count(2);
+    assert_eq!(histogram.edges(), vec![2.5, 3.0, 3.5]);
+    assert_eq!(histogram.values(), vec![0.0, 2.0]);
+}
+
+#[test]
+fn bins_edges() {
+    let mut histogram = Histogram::from_samples(vec![-1.0, 0.0, 0.5, 1.0, 3.0, 4.0]);
+    histogram.bins = Bins::Edges(vec![0.0, 1.0, 3.0]);
+    assert_eq!(histogram.edges(), vec![0.0, 1.0, 3.0]);
+    assert_eq!(histogram.values(), vec![2.0, 2.0]);
+
+    histogram.bins = Bins::Edges(vec![0.0, 2.0, 1.0]);
+    assert!(histogram.edges().is_empty());
+    histogram.bins = Bins::Edges(vec![0.0]);
+    assert!(histogram.edges().is_empty());
+    histogram.bins = Bins::Edges(vec![0.0, f64::INFINITY]);
+    assert!(histogram.edges().is_empty());
+}
+
+#[test]
+fn bins_sturges() {
+    let samples: Vec<f64> = (0..8).map(f64::from).collect();
+    let histogram = Histogram::from_samples(samples);
+    // ceil(log2(8)) + 1
+    assert_eq!(histogram.edges().len(), 5);
+    assert_eq!(histogram.values(), vec![2.0, 2.0, 2.0, 2.0]);
+}
+
+#[test]
+fn bins_freedman_diaconis() {
+    let samples: Vec<f64> = (0..=8).map(f64::from).collect();
+    let mut histogram = Histogram::from_samples(samples);
+    histogram.bins = Bins::FreedmanDiaconis;
+    // IQR = 4, so the width is 2 * 4 / 9^(1/3) and there are 3 bins.
+    assert_eq!(histogram.edges().len(), 4);
+    assert_eq!(histogram.values().iter().sum::<f64>(), 9.0);
+
+    histogram.samples = vec![0.0, 1.0, 1.0, 1.0, 1.0, 1.0, 2.0];
+    // IQR = 0, so Sturges' rule is used.
+    assert_eq!(histogram.edges().len(), 5);
+}
+
+#[test]
+fn bins_freedman_diaconis_outlier() {
+    let mut samples: Vec<f64> = (0..1000).map(|i| f64::from(i % 2)).collect();
+    samples.push(1e12);
+    let mut histogram = Histogram::from_samples(samples);
+    histogram.bins = Bins::FreedmanDiaconis;
+    // The rule gives ~1e12 bins, so Sturges' rule is used.
+    assert_eq!(histogram.edges().len(), 12);
+    assert_eq!(histogram.values().iter().sum::<f64>(), 1001.0);
+
+    histogram.samples = vec![0.0, 0.0, 5e-324, 5e-324, 1.0];
+    // The width is subnormal.
+    assert_eq!(histogram.edges().len(), 5);
+}
+
+#[test]
+fn interquartile_range_interpolates() {
+    assert_eq!(interquartile_range(&[1.0, 2.0, 3.0, 4.0]), 1.5);
+    assert_eq!(interquartile_range(&[5.0]), 0.0);
+}
+
+#[test]
+fn histogram_non_finite_samples() {
+    let mut histogram = Histogram::from_samples(vec![0.0, f64::NAN, 2.0, f64::INFINITY]);
+    histogram.bins = Bins::Count(2);
+    assert_eq!(histogram.edges(), vec![0.0, 1.0, 2.0]);
+    assert_eq!(histogram.values(), vec![1.0, 1.0]);
+}
+
+#[test]
+fn histogram_weights() {
+    let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 2.0, f64::NAN]);
+    histogram.bins = Bins::Count(2);
+    histogram.weights = Some(vec![0.5, 2.0, 1.5, 10.0]);
+    assert_eq!(histogram.values(), vec![0.5, 3.5]);
+
+    histogram.weights = Some(vec![1.0]);
+    assert!(histogram.edges().is_empty());
+    assert!(histogram.values().is_empty());
+}
+
+#[test]
+fn normalization_probability() {
+    let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 1.0, 4.0]);
+    histogram.bins = Bins::Edges(vec![0.0, 2.0, 4.0]);
+    histogram.normalization = Normalization::Probability;
+    assert_eq!(histogram.values(), vec![0.75, 0.25]);
+}
+
+#[test]
+fn normalization_density() {
+    let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 1.0, 4.0]);
+    histogram.bins = Bins::Edges(vec![0.0, 2.0, 6.0]);
+    histogram.normalization = Normalization::Density;
+    assert_eq!(histogram.values(), vec![0.375, 0.0625]);
+}
+
+#[test]
+fn normalization_cumulative() {
+    let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 1.0, 4.0]);
+    histogram.bins = Bins::Edges(vec![0.0, 2.0, 3.0, 4.0]);
+    histogram.normalization = Normalization::Cumulative;
+    assert_eq!(histogram.values(), vec![3.0, 3.0, 4.0]);
+}
+
+#[test]
+fn normalization_cumulative_probability() {
+    let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 1.0, 4.0]);
+    histogram.bins = Bins::Edges(vec![0.0, 2.0, 3.0, 4.0]);
+    histogram.normalization = Normalization::CumulativeProbability;
+    assert_eq!(histogram.values(), vec![0.75, 0.75, 1.0]);
+}
+
+#[test]
+fn normalization_without_samples_in_bins() {
+    let mut histogram = Histogram::from_samples(vec![10.0]);
+    histogram.bins = Bins::Edges(vec![0.0, 1.0]);
+    histogram.normalization = Normalization::Density;
+    assert_eq!(histogram.values(), vec![0.0]);
+}
+
+#[test]
+fn histogram_to_plot_bars() {
+    let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 4.0]);
+    histogram.bins = Bins::Count(2);
+    assert_eq!(
+        histogram.to_plot().to_string(),
+        "\t\\addplot[\n\t\tybar interval,\n\t] coordinates {\n\t\t(0,2)\n\t\t(2,1)\n\t\t(4,1)\n\t};"
+    );
+}
+
+#[test]
+fn histogram_to_plot_steps() {
+    let mut histogram = Histogram::from_samples(vec![0.0, 1.0, 4.0]);
+    histogram.bins = Bins::Count(2);
+    histogram.style = HistogramStyle::Steps;
+    assert_eq!(
+        histogram.to_plot().to_string(),
+        "\t\\addplot[\n\t\tconst plot mark left,\n\t] coordinates {\n\t\t(0,2)\n\t\t(2,1)\n\t\t(4,1)\n\t};"
+    );
+}
+
+#[test]
+fn histogram_to_plot_empty() {
+    let histogram = Histogram::new();
+    assert_eq!(
+        histogram.to_plot().to_string(),
+        "\t\\addplot[\n\t\tybar interval,\n\t] coordinates {\n\t};"
+    );
+}
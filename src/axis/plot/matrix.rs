@@ -0,0 +1,247 @@
+use crate::axis::plot::{add_key, fmt_addplot, PlotKey};
+use crate::axis::AxisKey;
+use crate::{number::NumberFormat, preamble::Preamble};
+use std::{fmt, io};
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{axis::Axis, Picture};
+
+/// Matrix plot (heatmap) inside an [`Axis`].
+///
+/// Every value of the matrix is drawn as a cell colored according to the
+/// colormap of the [`Axis`] (see [`AxisKey::Colormap`]). The first row of the
+/// matrix is at the top. Rows and columns are numbered from zero; their labels
+/// (if any) are shown as tick labels.
+///
+/// Adding a [`MatrixPlot`] to an [`Axis`] environment is equivalent to:
+///
+/// ```text
+/// \addplot[matrix plot*, point meta=explicit, PlotKeys]
+///     % cells;
+/// ```
+///
+/// The [`Axis`] also needs the keys returned by [`MatrixPlot::axis_keys`].
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::axis::{plot::matrix::MatrixPlot, Axis, AxisKey, Colorbar};
+///
+/// let rows = vec![vec![0.9, 0.1], vec![0.2, 0.8]];
+/// let mut matrix = MatrixPlot::from_rows(rows);
+/// matrix.row_labels = vec![String::from("cat"), String::from("dog")];
+/// matrix.column_labels = matrix.row_labels.clone();
+/// matrix.annotate = true;
+///
+/// let mut axis = Axis::new();
+/// for key in matrix.axis_keys() {
+///     axis.add_key(key);
+/// }
+/// axis.add_key(AxisKey::Colorbar(Colorbar::Right));
+/// axis.plots.push(matrix.into());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct MatrixPlot {
+    keys: Vec<PlotKey>,
+    /// Values of the matrix, row by row. All rows need to have the same
+    /// length; otherwise nothing is plotted.
+    pub values: Vec<Vec<f64>>,
+    /// Labels of the rows. If empty, the row numbers are shown. Otherwise,
+    /// there has to be one label per row. See [`AxisKey::YTickLabels`].
+    pub row_labels: Vec<String>,
+    /// Labels of the columns. If empty, the column numbers are shown.
+    /// Otherwise, there has to be one label per column. See
+    /// [`AxisKey::XTickLabels`].
+    pub column_labels: Vec<String>,
+    /// Whether every cell is annotated with its value.
+    pub annotate: bool,
+    /// Format of the numbers in the cells. If [`None`], the
+    /// [`Picture::number_format`] is used (or the default format if the plot is
+    /// not inside a [`Picture`]).
+    pub number_format: Option<NumberFormat>,
+}
+
+impl fmt::Display for MatrixPlot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_with(f, NumberFormat::default())
+    }
+}
+
+impl MatrixPlot {
+    /// Same as [`fmt::Display`], but `number_format` is used for the cells
+    /// unless the plot has its own [`MatrixPlot::number_format`].
+    pub(crate) fn fmt_with(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        number_format: NumberFormat,
+    ) -> fmt::Result {
+        let number_format = self.number_format.unwrap_or(number_format);
+
+        fmt_addplot(f, "addplot", &self.all_keys())?;
+        writeln!(f, " coordinates {{")?;
+
+        for (x, y, meta) in self.cells() {
+            let meta = number_format.display(meta);
+            writeln!(f, "\t\t({x},{y})\t[{meta}]")?;
+        }
+
+        write!(f, "\t}};")?;
+
+        Ok(())
+    }
+}
+
+impl MatrixPlot {
+    /// Creates a new, empty matrix plot.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::matrix::MatrixPlot;
+    ///
+    /// let mut matrix = MatrixPlot::new();
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Creates a matrix plot from the rows of a matrix.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::matrix::MatrixPlot;
+    ///
+    /// let matrix = MatrixPlot::from_rows(vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+    /// assert_eq!(matrix.values[0][1], 0.5);
+    /// ```
+    pub fn from_rows(rows: Vec<Vec<f64>>) -> Self {
+        MatrixPlot {
+            values: rows,
+            ..Default::default()
+        }
+    }
+    /// Add a key to control the appearance of the plot. This will overwrite
+    /// any previous mutually exclusive key.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::plot::{matrix::MatrixPlot, PlotKey};
+    ///
+    /// let mut matrix = MatrixPlot::new();
+    /// matrix.add_key(PlotKey::Custom(String::from("opacity=0.5")));
+    /// ```
+    pub fn add_key(&mut self, key: PlotKey) {
+        add_key(&mut self.keys, key);
+    }
+    /// Return the keys that the [`Axis`] needs to show the matrix plot. These
+    /// place a tick with its label at every labeled row and column (if any),
+    /// put the first row at the top, and remove the empty space around the
+    /// cells.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::axis::{plot::matrix::MatrixPlot, Axis};
+    ///
+    /// let matrix = MatrixPlot::from_rows(vec![vec![1.0, 0.5], vec![0.5, 1.0]]);
+    /// let mut axis = Axis::new();
+    /// for key in matrix.axis_keys() {
+    ///     axis.add_key(key);
+    /// }
+    /// ```
+    pub fn axis_keys(&self) -> Vec<AxisKey> {
+        let ticks = |labels: &[String]| (0..labels.len()).map(|i| i as f64).collect();
+
+        let mut keys = Vec::new();
+        if !self.column_labels.is_empty() {
+            keys.push(AxisKey::XTick(ticks(&self.column_labels)));
+            keys.push(AxisKey::XTickLabels(self.column_labels.clone()));
+        }
+        if !self.row_labels.is_empty() {
+            keys.push(AxisKey::YTick(ticks(&self.row_labels)));
+            keys.push(AxisKey::YTickLabels(self.row_labels.clone()));
+        }
+        keys.push(AxisKey::ReverseYAxis);
+        keys.push(AxisKey::EnlargeLimits(false));
+        keys.push(AxisKey::AxisOnTop);
+
+        keys
+    }
+    /// Number of columns of the matrix, or [`None`] if the rows do not all
+    /// have the same length or the labels do not match the matrix.
+    fn columns(&self) -> Option<usize> {
+        let columns = self.values.first().map_or(0, Vec::len);
+        let valid = self.values.iter().all(|row| row.len() == columns)
+            && (self.row_labels.is_empty() || self.row_labels.len() == self.values.len())
+            && (self.column_labels.is_empty() || self.column_labels.len() == columns);
+
+        valid.then_some(columns)
+    }
+    /// Return the `(column, row, value)` of every cell, row by row.
+    fn cells(&self) -> Vec<(usize, usize, f64)> {
+        if self.columns().is_none() {
+            return Vec::new();
+        }
+
+        self.values
+            .iter()
+            .enumerate()
+            .flat_map(|(i, row)| row.iter().enumerate().map(move |(j, &value)| (j, i, value)))
+            .collect()
+    }
+    /// Return the keys written in the options of the `\addplot` command i.e.
+    /// the matrix plot keys overwritten by the user keys.
+    fn all_keys(&self) -> Vec<PlotKey> {
+        let mut keys = vec![
+            PlotKey::Custom(String::from("matrix plot*")),
+            PlotKey::Custom(String::from("point meta=explicit")),
+            PlotKey::MeshCols(self.columns().unwrap_or(0)),
+        ];
+        if self.annotate {
+            keys.push(PlotKey::Custom(String::from("nodes near coords")));
+            keys.push(PlotKey::Custom(String::from(
+                "nodes near coords style={anchor=center}",
+            )));
+        }
+        for key in self.keys.iter() {
+            add_key(&mut keys, key.clone());
+        }
+
+        keys
+    }
+    /// Write the plot such that the cells are read from the data file at
+    /// `path` (as written by [`MatrixPlot::write_table`]) instead of being
+    /// inlined.
+    pub(crate) fn fmt_table(&self, f: &mut fmt::Formatter<'_>, path: &str) -> fmt::Result {
+        fmt_addplot(f, "addplot", &self.all_keys())?;
+        write!(f, " table[meta=meta] {{{path}}};")
+    }
+    /// Write the cells as a whitespace separated table with a header row.
+    pub(crate) fn write_table<W: io::Write>(
+        &self,
+        mut writer: W,
+        number_format: NumberFormat,
+    ) -> io::Result<()> {
+        let number_format = self.number_format.unwrap_or(number_format);
+        writeln!(writer, "x y meta")?;
+
+        for (x, y, meta) in self.cells() {
+            let meta = number_format.display(meta);
+            writeln!(writer, "{x} {y} {meta}")?;
+        }
+
+        writer.flush()
+    }
+    /// Add the packages and libraries needed by the plot to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        for key in self.keys.iter() {
+            key.add_requirements(preamble);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,109 @@
+use super::*;
+
+#[test]
+fn matrix_plot_new() {
+    let matrix = MatrixPlot::new();
+    assert!(matrix.keys.is_empty());
+    assert!(matrix.values.is_empty());
+    assert!(matrix.row_labels.is_empty());
+    assert!(matrix.column_labels.is_empty());
+    assert!(!matrix.annotate);
+    assert!(matrix.number_format.is_none());
+}
+
+#[test]
+fn matrix_plot_to_string() {
+    let mut matrix = MatrixPlot::from_rows(vec![vec![1.0, 0.5], vec![0.25, 1.0]]);
+    assert_eq!(
+        matrix.to_string(),
+        "\t\\addplot[\n\t\tmatrix plot*,\n\t\tpoint meta=explicit,\n\t\tmesh/cols=2,\n\t] coordinates {\n\t\t(0,0)\t[1]\n\t\t(1,0)\t[0.5]\n\t\t(0,1)\t[0.25]\n\t\t(1,1)\t[1]\n\t};"
+    );
+
+    matrix.row_labels = vec![String::from("a"), String::from("b")];
+    matrix.column_labels = vec![String::from("c"), String::from("d")];
+    matrix.annotate = true;
+    matrix.add_key(PlotKey::Custom(String::from("opacity=0.5")));
+    assert_eq!(
+        matrix.to_string(),
+        "\t\\addplot[\n\t\tmatrix plot*,\n\t\tpoint meta=explicit,\n\t\tmesh/cols=2,\n\t\tnodes near coords,\n\t\tnodes near coords style={anchor=center},\n\t\topacity=0.5,\n\t] coordinates {\n\t\t(0,0)\t[1]\n\t\t(1,0)\t[0.5]\n\t\t(0,1)\t[0.25]\n\t\t(1,1)\t[1]\n\t};"
+    );
+}
+
+#[test]
+fn matrix_plot_user_keys() {
+    let mut matrix = MatrixPlot::from_rows(vec![vec![1.0, 0.5]]);
+    matrix.add_key(PlotKey::MeshCols(1));
+    assert_eq!(
+        matrix.to_string(),
+        "\t\\addplot[\n\t\tmatrix plot*,\n\t\tpoint meta=explicit,\n\t\tmesh/cols=1,\n\t] coordinates {\n\t\t(0,0)\t[1]\n\t\t(1,0)\t[0.5]\n\t};"
+    );
+}
+
+#[test]
+fn matrix_plot_invalid() {
+    let mut matrix = MatrixPlot::from_rows(vec![vec![1.0, 0.5], vec![0.25]]);
+    assert!(matrix.columns().is_none());
+    assert!(matrix.cells().is_empty());
+
+    matrix.values[1].push(1.0);
+    assert_eq!(matrix.columns(), Some(2));
+    matrix.row_labels = vec![String::from("a")];
+    assert!(matrix.columns().is_none());
+    matrix.row_labels.clear();
+    matrix.column_labels = vec![String::from("a")];
+    assert!(matrix.columns().is_none());
+}
+
+#[test]
+fn matrix_plot_axis_keys() {
+    let mut matrix = MatrixPlot::from_rows(vec![vec![1.0, 0.5]]);
+    let keys: Vec<String> = matrix.axis_keys().iter().map(|k| k.to_string()).collect();
+    assert_eq!(
+        keys,
+        vec!["y dir=reverse", "enlargelimits=false", "axis on top"]
+    );
+
+    matrix.row_labels = vec![String::from("a")];
+    matrix.column_labels = vec![String::from("b"), String::from("c")];
+    let keys: Vec<String> = matrix.axis_keys().iter().map(|k| k.to_string()).collect();
+    assert_eq!(
+        keys,
+        vec![
+            "xtick={0,1}",
+            "xticklabels={{b},{c}}",
+            "ytick={0}",
+            "yticklabels={{a}}",
+            "y dir=reverse",
+            "enlargelimits=false",
+            "axis on top"
+        ]
+    );
+}
+
+#[test]
+fn matrix_plot_axis_keys_twice() {
+    let mut matrix = MatrixPlot::from_rows(vec![vec![1.0, 0.5]]);
+    matrix.column_labels = vec![String::from("b"), String::from("c")];
+    let mut axis = Axis::new();
+    for key in matrix.axis_keys().into_iter().chain(matrix.axis_keys()) {
+        axis.add_key(key);
+    }
+    axis.add_key(AxisKey::EnlargeLimits(true));
+    let axis = axis.to_string();
+    assert_eq!(axis.matches("xticklabels=").count(), 1);
+    assert_eq!(axis.matches("axis on top").count(), 1);
+    assert!(!axis.contains("enlargelimits=false"));
+    assert!(axis.contains("enlargelimits=true"));
+}
+
+#[test]
+fn matrix_plot_write_table() {
+    let mut matrix = MatrixPlot::from_rows(vec![vec![1.0, 0.5]]);
+    matrix.row_labels = vec![String::from("a")];
+    let mut table = Vec::new();
+    matrix.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "x y meta\n0 0 1\n1 0 0.5\n"
+    );
+}
@@ -1,4 +1,6 @@
 use super::*;
+use crate::color::NamedColor;
+use crate::number::Notation;
 
 #[test]
 fn error_direction_to_string() {
@@ -50,8 +52,8 @@ fn plot_type2d_tested() {
         Type2D::XComb => (),
         Type2D::YComb => (),
         Type2D::OnlyMarks => (),
+        Type2D::YBarInterval => (),
     }
-    assert!(true);
 }
 
 #[test]
@@ -81,38 +83,42 @@ fn type_2d_to_string() {
     assert_eq!(Type2D::JumpMid.to_string(), String::from("jump mark mid"));
     assert_eq!(
         Type2D::XBar {
-            bar_width: 0.5,
-            bar_shift: 1.0
+            bar_width: Length::axis(0.5),
+            bar_shift: Length::axis(1.0)
         }
         .to_string(),
         String::from("xbar, bar width=0.5, bar shift=1")
     );
     assert_eq!(
         Type2D::XBar {
-            bar_shift: 1.0,
-            bar_width: 0.5
+            bar_shift: Length::axis(1.0),
+            bar_width: Length::axis(0.5)
         }
         .to_string(),
         String::from("xbar, bar width=0.5, bar shift=1")
     );
     assert_eq!(
         Type2D::YBar {
-            bar_width: 0.5,
-            bar_shift: 1.0
+            bar_width: Length::axis(0.5),
+            bar_shift: Length::axis(1.0)
         }
         .to_string(),
         String::from("ybar, bar width=0.5, bar shift=1")
     );
     assert_eq!(
         Type2D::YBar {
-            bar_shift: 1.0,
-            bar_width: 0.5
+            bar_shift: Length::axis(1.0),
+            bar_width: Length::axis(0.5)
         }
         .to_string(),
         String::from("ybar, bar width=0.5, bar shift=1")
     );
     assert_eq!(Type2D::XComb.to_string(), String::from("xcomb"));
     assert_eq!(Type2D::YComb.to_string(), String::from("ycomb"));
+    assert_eq!(
+        Type2D::YBarInterval.to_string(),
+        String::from("ybar interval")
+    );
     assert_eq!(Type2D::OnlyMarks.to_string(), String::from("only marks"));
 }
 
@@ -133,8 +139,18 @@ fn plot_keys_tested() {
         PlotKey::XErrorDirection(_) => (),
         PlotKey::YError(_) => (),
         PlotKey::YErrorDirection(_) => (),
+        PlotKey::Type3D(_) => (),
+        PlotKey::MeshRows(_) => (),
+        PlotKey::MeshCols(_) => (),
+        PlotKey::PointMeta(_) => (),
+        PlotKey::Scatter => (),
+        PlotKey::ScatterSrc(_) => (),
+        PlotKey::NamePath(_) => (),
+        PlotKey::ForgetPlot => (),
+        PlotKey::Color(_) => (),
+        PlotKey::Fill(_) => (),
+        PlotKey::LineWidth(_) => (),
     }
-    assert!(true);
 }
 
 #[test]
@@ -258,6 +274,27 @@ fn plot_2d_new() {
     let plot = Plot2D::new();
     assert!(plot.coordinates.is_empty());
     assert!(plot.keys.is_empty());
+    assert!(!plot.closed_cycle);
+}
+
+#[test]
+fn plot_2d_closed_cycle_to_string() {
+    let mut plot = Plot2D::new();
+    plot.coordinates.push((1.0, -1.0).into());
+    plot.closed_cycle = true;
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[] coordinates {\n\t\t(1,-1)\n\t} \\closedcycle;"
+    );
+}
+
+#[test]
+fn plot_2d_type_2d() {
+    let mut plot = Plot2D::new();
+    assert!(plot.type_2d().is_none());
+    plot.add_key(PlotKey::Scatter);
+    plot.add_key(PlotKey::Type2D(Type2D::OnlyMarks));
+    assert!(matches!(plot.type_2d(), Some(Type2D::OnlyMarks)));
 }
 
 #[test]
@@ -320,6 +357,7 @@ fn plot_standalone_string() {
     assert_eq!(
         "\\documentclass{standalone}
 \\usepackage{pgfplots}
+\\pgfplotsset{compat=1.16}
 \\begin{document}
 \\begin{tikzpicture}
 \\begin{axis}
@@ -360,3 +398,423 @@ fn plot_2d_to_string() {
         "\t\\addplot[\n\t\tsharp plot,\n\t\terror bars/x explicit,\n\t\terror bars/x dir=both,\n\t] coordinates {\n\t\t(1,-1)\n\t\t(2,-2)\n\t\t(3,-3)\n\t};"
     );
 }
+
+#[test]
+fn plot_2d_write_table() {
+    let mut plot = Plot2D::new();
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(String::from_utf8(table).unwrap(), "x y\n");
+
+    plot.coordinates.push((1.0, -1.0).into());
+    plot.coordinates.push((2.5, -2.0).into());
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(String::from_utf8(table).unwrap(), "x y\n1 -1\n2.5 -2\n");
+
+    plot.coordinates.push((3.0, -3.0, Some(0.1), None).into());
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "x y ex ey\n1 -1 0 0\n2.5 -2 0 0\n3 -3 0.1 0\n"
+    );
+}
+
+#[test]
+fn plot_2d_write_table_asymmetric_errors() {
+    let mut plot = Plot2D::new();
+    plot.coordinates
+        .push((1.0, -1.0, Some(0.1), Some(0.2)).into());
+    plot.coordinates.push((2.0, -2.0).into());
+    plot.coordinates[1].error_y_plus = Some(0.5);
+    plot.coordinates[1].error_y_minus = Some(0.25);
+
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "x y exp exm eyp eym\n1 -1 0.1 0.1 0.2 0.2\n2 -2 0 0 0.5 0.25\n"
+    );
+}
+
+#[test]
+fn plot_2d_asymmetric_errors_to_string() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::YError(ErrorCharacter::Absolute));
+    plot.add_key(PlotKey::YErrorDirection(ErrorDirection::Both));
+    plot.coordinates.push((1.0, -1.0).into());
+    plot.coordinates[0].error_y_plus = Some(0.5);
+    plot.coordinates[0].error_y_minus = Some(0.25);
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\terror bars/y explicit,\n\t\terror bars/y dir=both,\n\t] coordinates {\n\t\t(1,-1)\t+= (0,0.5)\t-= (0,0.25)\n\t};"
+    );
+}
+
+#[test]
+fn plot_2d_write_standalone() {
+    let mut plot = Plot2D::new();
+    plot.coordinates = vec![(1.0, -1.0).into(), (2.0, -4.0).into()];
+
+    let mut buffer = Vec::new();
+    plot.write_standalone(&mut buffer).unwrap();
+    assert_eq!(plot.standalone_string(), String::from_utf8(buffer).unwrap());
+}
+
+#[test]
+fn plot_2d_number_format() {
+    let mut plot = Plot2D::new();
+    plot.coordinates.push((0.1 + 0.2, f64::NAN).into());
+    plot.coordinates
+        .push((1e-7, f64::NEG_INFINITY, Some(0.05), None).into());
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[] coordinates {\n\t\t(0.30000000000000004,nan)\n\t\t(1e-7,-inf)\t+- (0.05,0)\n\t};"
+    );
+
+    plot.number_format = Some(NumberFormat {
+        significant_digits: Some(2),
+        notation: Notation::Scientific,
+    });
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[] coordinates {\n\t\t(3e-1,nan)\n\t\t(1e-7,-inf)\t+- (5e-2,0e0)\n\t};"
+    );
+
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "x y ex ey\n3e-1 nan 0e0 0e0\n1e-7 -inf 5e-2 0e0\n"
+    );
+}
+
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn plot_type3d_tested() {
+    let type_3d = Type3D::Surf;
+    match type_3d {
+        Type3D::Surf => (),
+        Type3D::Mesh => (),
+        Type3D::Scatter => (),
+        Type3D::Wireframe => (),
+    }
+}
+
+#[test]
+fn type_3d_to_string() {
+    assert_eq!(Type3D::Surf.to_string(), String::from("surf"));
+    assert_eq!(Type3D::Mesh.to_string(), String::from("mesh"));
+    assert_eq!(
+        Type3D::Scatter.to_string(),
+        String::from("scatter, only marks")
+    );
+    assert_eq!(
+        Type3D::Wireframe.to_string(),
+        String::from("mesh, draw=black")
+    );
+}
+
+#[test]
+fn plot_key_type_3d_to_string() {
+    assert_eq!(
+        PlotKey::Type3D(Type3D::Surf).to_string(),
+        String::from("surf")
+    );
+}
+
+#[test]
+fn plot_key_mesh_rows_to_string() {
+    assert_eq!(
+        PlotKey::MeshRows(10).to_string(),
+        String::from("mesh/rows=10")
+    );
+}
+
+#[test]
+fn plot_key_mesh_cols_to_string() {
+    assert_eq!(
+        PlotKey::MeshCols(20).to_string(),
+        String::from("mesh/cols=20")
+    );
+}
+
+#[test]
+fn plot_3d_new() {
+    let plot = Plot3D::new();
+    assert!(plot.keys.is_empty());
+    assert!(plot.coordinates.is_empty());
+}
+
+#[test]
+fn plot_3d_add_key() {
+    let mut plot = Plot3D::new();
+    plot.add_key(PlotKey::Type3D(Type3D::Surf));
+    plot.add_key(PlotKey::Type3D(Type3D::Mesh));
+    plot.add_key(PlotKey::Custom(String::from("opacity=0.5")));
+    plot.add_key(PlotKey::Custom(String::from("opacity=0.5")));
+    assert_eq!(plot.keys.len(), 3);
+    assert!(matches!(plot.keys[0], PlotKey::Type3D(Type3D::Mesh)));
+}
+
+#[test]
+fn plot_3d_from_grid() {
+    let plot = Plot3D::from_grid(&[0.0, 1.0, 2.0], &[-1.0, 1.0], |x, y| x * y);
+    let coordinates: Vec<_> = plot.coordinates.iter().map(|c| (c.x, c.y, c.z)).collect();
+    assert_eq!(
+        coordinates,
+        vec![
+            (0.0, -1.0, -0.0),
+            (1.0, -1.0, -1.0),
+            (2.0, -1.0, -2.0),
+            (0.0, 1.0, 0.0),
+            (1.0, 1.0, 1.0),
+            (2.0, 1.0, 2.0),
+        ]
+    );
+    assert_eq!(
+        plot.keys.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+        vec!["mesh/rows=2", "mesh/cols=3"]
+    );
+}
+
+#[test]
+fn plot_3d_to_string() {
+    let mut plot = Plot3D::new();
+    assert_eq!(plot.to_string(), "\t\\addplot3[] coordinates {\n\t};");
+
+    plot.add_key(PlotKey::Type3D(Type3D::Surf));
+    plot.coordinates.push((1.0, -1.0, 0.5).into());
+    plot.coordinates.push((2.0, -2.0, 1.5).into());
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot3[\n\t\tsurf,\n\t] coordinates {\n\t\t(1,-1,0.5)\n\t\t(2,-2,1.5)\n\t};"
+    );
+}
+
+#[test]
+fn plot_3d_write_table() {
+    let mut plot = Plot3D::new();
+    plot.coordinates.push((1.0, -1.0, 0.5).into());
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(String::from_utf8(table).unwrap(), "x y z\n1 -1 0.5\n");
+}
+
+#[test]
+fn plot_3d_standalone_string() {
+    let plot = Plot3D::new();
+    assert_eq!(
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\pgfplotsset{compat=1.16}
+\begin{document}
+\begin{tikzpicture}
+\begin{axis}
+	\addplot3[] coordinates {
+	};
+\end{axis}
+\end{tikzpicture}
+\end{document}"#,
+        plot.standalone_string()
+    );
+}
+
+#[test]
+fn plot_from() {
+    assert!(matches!(Plot::from(Plot2D::new()), Plot::Plot2D(_)));
+    assert!(matches!(Plot::from(Plot3D::new()), Plot::Plot3D(_)));
+    assert!(matches!(
+        Plot::from(contour::ContourPlot::new()),
+        Plot::ContourPlot(_)
+    ));
+    assert!(matches!(
+        Plot::from(matrix::MatrixPlot::new()),
+        Plot::MatrixPlot(_)
+    ));
+}
+
+#[test]
+fn point_meta_source_to_string() {
+    assert_eq!(PointMetaSource::Explicit.to_string(), "explicit");
+    assert_eq!(
+        PointMetaSource::ExplicitSymbolic.to_string(),
+        "explicit symbolic"
+    );
+    assert_eq!(PointMetaSource::X.to_string(), "x");
+    assert_eq!(PointMetaSource::Y.to_string(), "y");
+    assert_eq!(PointMetaSource::Z.to_string(), "z");
+}
+
+#[test]
+fn plot_key_point_meta_to_string() {
+    assert_eq!(
+        PlotKey::PointMeta(PointMetaSource::Explicit).to_string(),
+        "point meta=explicit"
+    );
+    assert_eq!(
+        PlotKey::PointMeta(PointMetaSource::ExplicitSymbolic).to_string(),
+        "point meta=explicit symbolic"
+    );
+}
+
+#[test]
+fn plot_key_scatter_to_string() {
+    assert_eq!(PlotKey::Scatter.to_string(), "scatter");
+}
+
+#[test]
+fn plot_key_scatter_src_to_string() {
+    assert_eq!(
+        PlotKey::ScatterSrc(PointMetaSource::Y).to_string(),
+        "scatter src=y"
+    );
+}
+
+#[test]
+fn plot_key_name_path_to_string() {
+    assert_eq!(
+        PlotKey::NamePath(String::from("upper")).to_string(),
+        "name path=upper"
+    );
+}
+
+#[test]
+fn plot_key_forget_plot_to_string() {
+    assert_eq!(PlotKey::ForgetPlot.to_string(), "forget plot");
+}
+
+#[test]
+fn plot_key_color_to_string() {
+    assert_eq!(
+        PlotKey::Color(Color::Named(NamedColor::Red)).to_string(),
+        "color=red"
+    );
+    assert_eq!(
+        PlotKey::Fill(Color::mix(NamedColor::Gray, 20.0, NamedColor::White)).to_string(),
+        "fill=gray!20!white"
+    );
+    assert_eq!(
+        PlotKey::Fill(Color::Rgb(1, 2, 3)).to_string(),
+        "fill={rgb,255:red,1;green,2;blue,3}"
+    );
+}
+
+#[test]
+fn plot_key_line_width_to_string() {
+    assert_eq!(
+        PlotKey::LineWidth(Length::pt(1.5)).to_string(),
+        "line width=1.5pt"
+    );
+}
+
+#[test]
+fn plot_2d_legend_image() {
+    let mut plot = Plot2D::new();
+    assert_eq!(plot.legend_image(), "");
+    plot.add_key(PlotKey::Type2D(Type2D::SharpPlot));
+    plot.add_key(PlotKey::Custom(String::from("dashed")));
+    assert_eq!(plot.legend_image(), "sharp plot, dashed");
+
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::YBar {
+        bar_width: Length::pt(10.0),
+        bar_shift: Length::pt(5.0),
+    }));
+    plot.add_key(PlotKey::NamePath(String::from("bars")));
+    plot.add_key(PlotKey::ForgetPlot);
+    plot.add_key(PlotKey::Fill(Color::Named(NamedColor::Red)));
+    assert_eq!(plot.legend_image(), "ybar, fill=red");
+}
+
+#[test]
+fn plot_2d_add_requirements() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Type2D(Type2D::YBar {
+        bar_width: Length::pt(10.0),
+        bar_shift: Length::pt(0.0),
+    }));
+    let mut preamble = Preamble::new();
+    preamble.set_compat("1.5");
+    plot.add_requirements(&mut preamble);
+    assert!(preamble.to_string().contains("compat=1.5"));
+
+    plot.add_key(PlotKey::Type2D(Type2D::XBar {
+        bar_width: Length::axis(0.8),
+        bar_shift: Length::pt(0.0),
+    }));
+    plot.add_requirements(&mut preamble);
+    assert!(preamble.to_string().contains("compat=1.7"));
+}
+
+#[test]
+fn plot_2d_legend_entry() {
+    let mut plot = Plot2D::new();
+    assert_eq!(plot.legend_entry(), None);
+    plot.legend = Some(String::from("a"));
+    assert_eq!(plot.legend_entry(), Some("a"));
+    plot.add_key(PlotKey::ForgetPlot);
+    assert_eq!(plot.legend_entry(), None);
+}
+
+#[test]
+fn plot_2d_name_path_or() {
+    let mut plot = Plot2D::new();
+    assert_eq!(plot.name_path_or(String::from("first")), "first");
+    assert_eq!(plot.name_path_or(String::from("second")), "first");
+    assert_eq!(plot.keys.len(), 1);
+    assert_eq!(plot.keys[0].to_string(), "name path=first");
+}
+
+#[test]
+fn plot_2d_point_meta() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Scatter);
+    plot.add_key(PlotKey::PointMeta(PointMetaSource::Explicit));
+    plot.coordinates
+        .push((1.0, 2.0, PointMeta::Numeric(0.5)).into());
+    plot.coordinates.push((3.0, 4.0).into());
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot[\n\t\tscatter,\n\t\tpoint meta=explicit,\n\t] coordinates {\n\t\t(1,2)\t[0.5]\n\t\t(3,4)\n\t};"
+    );
+
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "x y meta\n1 2 0.5\n3 4 nan\n"
+    );
+
+    plot.coordinates[1].error_y = Some(0.1);
+    plot.coordinates[1].point_meta = Some(PointMeta::Symbolic(String::from("class b")));
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(
+        String::from_utf8(table).unwrap(),
+        "x y ex ey meta\n1 2 0 0 0.5\n3 4 0 0.1 {class b}\n"
+    );
+}
+
+#[test]
+fn plot_3d_point_meta() {
+    let mut plot = Plot3D::new();
+    let mut coordinate: Coordinate3D = (1.0, 2.0, 3.0).into();
+    coordinate.point_meta = Some(PointMeta::Symbolic(String::from("a")));
+    plot.coordinates.push(coordinate);
+    assert_eq!(
+        plot.to_string(),
+        "\t\\addplot3[] coordinates {\n\t\t(1,2,3)\t[a]\n\t};"
+    );
+
+    let mut table = Vec::new();
+    plot.write_table(&mut table, NumberFormat::new()).unwrap();
+    assert_eq!(String::from_utf8(table).unwrap(), "x y z meta\n1 2 3 {a}\n");
+}
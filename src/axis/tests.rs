@@ -1,5 +1,8 @@
 use super::*;
-use crate::axis::plot::{PlotKey, *};
+use crate::axis::plot::{error_band::ErrorBand, PlotKey, *};
+use crate::color::NamedColor;
+use crate::length::ColumnPreset;
+use crate::number::Notation;
 
 #[test]
 fn scale_to_string() {
@@ -24,8 +27,601 @@ fn axis_keys_tested() {
         AxisKey::Title(_) => (),
         AxisKey::XLabel(_) => (),
         AxisKey::YLabel(_) => (),
+        AxisKey::ZMode(_) => (),
+        AxisKey::ZLabel(_) => (),
+        AxisKey::View {
+            azimuth: _,
+            elevation: _,
+        } => (),
+        AxisKey::Colormap(_) => (),
+        AxisKey::Colorbar(_) => (),
+        AxisKey::PointMetaMin(_) => (),
+        AxisKey::PointMetaMax(_) => (),
+        AxisKey::Stack(_) => (),
+        AxisKey::XMin(_) => (),
+        AxisKey::XMax(_) => (),
+        AxisKey::YMin(_) => (),
+        AxisKey::YMax(_) => (),
+        AxisKey::ZMin(_) => (),
+        AxisKey::ZMax(_) => (),
+        AxisKey::XTick(_) => (),
+        AxisKey::YTick(_) => (),
+        AxisKey::ZTick(_) => (),
+        AxisKey::XTickLabels(_) => (),
+        AxisKey::YTickLabels(_) => (),
+        AxisKey::ZTickLabels(_) => (),
+        AxisKey::MinorXTickNum(_) => (),
+        AxisKey::MinorYTickNum(_) => (),
+        AxisKey::MinorZTickNum(_) => (),
+        AxisKey::XTickLabelFormat(_) => (),
+        AxisKey::YTickLabelFormat(_) => (),
+        AxisKey::ZTickLabelFormat(_) => (),
+        AxisKey::XTickLabelRotation(_) => (),
+        AxisKey::YTickLabelRotation(_) => (),
+        AxisKey::LegendPos(_) => (),
+        AxisKey::LegendColumns(_) => (),
+        AxisKey::LegendStyle(_) => (),
+        AxisKey::ReverseLegend => (),
+        AxisKey::Grid(_) => (),
+        AxisKey::GridStyle(_) => (),
+        AxisKey::MajorGridStyle(_) => (),
+        AxisKey::MinorGridStyle(_) => (),
+        AxisKey::AxisLines(_) => (),
+        AxisKey::XAxisLine(_) => (),
+        AxisKey::YAxisLine(_) => (),
+        AxisKey::AxisLineStyle(_) => (),
+        AxisKey::AxisOnTop => (),
+        AxisKey::HideAxis => (),
+        AxisKey::HideXAxis => (),
+        AxisKey::HideYAxis => (),
+        AxisKey::HideZAxis => (),
+        AxisKey::Width(_) => (),
+        AxisKey::Height(_) => (),
+        AxisKey::ScaleOnlyAxis => (),
+        AxisKey::Aspect(_) => (),
+        AxisKey::EnlargeLimits(_) => (),
+        AxisKey::ReverseXAxis => (),
+        AxisKey::ReverseYAxis => (),
+        AxisKey::ReverseZAxis => (),
     }
-    assert!(true);
+}
+
+#[test]
+fn tick_notations_tested() {
+    let notation = TickNotation::Auto;
+    match notation {
+        TickNotation::Auto => (),
+        TickNotation::Fixed => (),
+        TickNotation::FixedZerofill => (),
+        TickNotation::Scientific => (),
+    }
+}
+
+#[test]
+fn axis_key_limits_to_string() {
+    assert_eq!(AxisKey::XMin(-1.5).to_string(), "xmin=-1.5");
+    assert_eq!(AxisKey::XMax(2.0).to_string(), "xmax=2");
+    assert_eq!(AxisKey::YMin(0.0).to_string(), "ymin=0");
+    assert_eq!(AxisKey::YMax(100.0).to_string(), "ymax=100");
+    assert_eq!(AxisKey::ZMin(-3.0).to_string(), "zmin=-3");
+    assert_eq!(AxisKey::ZMax(0.25).to_string(), "zmax=0.25");
+    assert_eq!(AxisKey::XMin(f64::NEG_INFINITY).to_string(), "xmin=-inf");
+    assert_eq!(AxisKey::YMax(f64::NAN).to_string(), "ymax=nan");
+    assert_eq!(AxisKey::ZMax(1e-7).to_string(), "zmax=1e-7");
+}
+
+#[test]
+fn axis_key_ticks_to_string() {
+    assert_eq!(
+        AxisKey::XTick(vec![0.0, 0.5, 1.0]).to_string(),
+        "xtick={0,0.5,1}"
+    );
+    assert_eq!(AxisKey::YTick(vec![-2.0]).to_string(), "ytick={-2}");
+    assert_eq!(AxisKey::ZTick(Vec::new()).to_string(), "ztick=\\empty");
+    assert_eq!(
+        AxisKey::XTick(vec![f64::INFINITY, 1e20]).to_string(),
+        "xtick={inf,1e20}"
+    );
+}
+
+#[test]
+fn axis_number_format() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::XMin(0.123456));
+    axis.add_key(AxisKey::YTick(vec![1234.0]));
+    axis.add_key(AxisKey::PointMetaMax(0.987));
+    axis.add_key(AxisKey::Aspect(Aspect::UnitVectorRatio(vec![
+        1.0,
+        1.0 / 3.0,
+    ])));
+    let format = NumberFormat {
+        significant_digits: Some(2),
+        notation: Notation::Auto,
+    };
+    assert_eq!(
+        AxisDisplay(&axis, format).to_string(),
+        "\\begin{axis}[\n\txmin=0.12,\n\tytick={1200},\n\tpoint meta max=0.99,\n\tunit vector ratio={1 0.33},\n]\n\\end{axis}"
+    );
+}
+
+/// Display an axis with a custom number format.
+struct AxisDisplay<'a>(&'a Axis, NumberFormat);
+impl fmt::Display for AxisDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt_with(f, self.1)
+    }
+}
+
+#[test]
+fn axis_key_tick_labels_to_string() {
+    assert_eq!(
+        AxisKey::XTickLabels(vec![String::from("a"), String::from("b, c")]).to_string(),
+        "xticklabels={{a},{b, c}}"
+    );
+    assert_eq!(
+        AxisKey::YTickLabels(vec![String::from("$x=1$")]).to_string(),
+        "yticklabels={{$x=1$}}"
+    );
+    assert_eq!(
+        AxisKey::ZTickLabels(Vec::new()).to_string(),
+        "zticklabels={}"
+    );
+}
+
+#[test]
+fn axis_key_tick_labels_unbalanced() {
+    assert_eq!(
+        AxisKey::XTickLabels(vec![String::from("{a}"), String::from("\\{b")]).to_string(),
+        "xticklabels={{{a}},{\\{b}}"
+    );
+    assert_eq!(
+        AxisKey::XTickLabels(vec![String::from("a}"), String::from("{b")]).to_string(),
+        "xticklabels={{a\\}},{\\{b}}"
+    );
+    assert_eq!(
+        AxisKey::YTickLabels(vec![String::from("a\\")]).to_string(),
+        "yticklabels={{a\\textbackslash{}}}"
+    );
+}
+
+#[test]
+fn axis_key_minor_tick_num_to_string() {
+    assert_eq!(AxisKey::MinorXTickNum(1).to_string(), "minor x tick num=1");
+    assert_eq!(AxisKey::MinorYTickNum(4).to_string(), "minor y tick num=4");
+    assert_eq!(AxisKey::MinorZTickNum(0).to_string(), "minor z tick num=0");
+}
+
+#[test]
+fn tick_label_format_to_string() {
+    assert_eq!(TickLabelFormat::default().to_string(), "");
+    let mut format = TickLabelFormat {
+        notation: TickNotation::Fixed,
+        precision: None,
+    };
+    assert_eq!(format.to_string(), "/pgf/number format/fixed");
+    format.notation = TickNotation::FixedZerofill;
+    format.precision = Some(2);
+    assert_eq!(
+        format.to_string(),
+        "/pgf/number format/fixed zerofill, /pgf/number format/precision=2"
+    );
+    format.notation = TickNotation::Scientific;
+    format.precision = Some(0);
+    assert_eq!(
+        format.to_string(),
+        "/pgf/number format/sci, /pgf/number format/precision=0"
+    );
+}
+
+#[test]
+fn axis_key_tick_label_format_to_string() {
+    let format = TickLabelFormat {
+        notation: TickNotation::Auto,
+        precision: Some(3),
+    };
+    assert_eq!(
+        AxisKey::XTickLabelFormat(format).to_string(),
+        "xticklabel style={/pgf/number format/precision=3}"
+    );
+    assert_eq!(
+        AxisKey::YTickLabelFormat(format).to_string(),
+        "yticklabel style={/pgf/number format/precision=3}"
+    );
+    assert_eq!(
+        AxisKey::ZTickLabelFormat(format).to_string(),
+        "zticklabel style={/pgf/number format/precision=3}"
+    );
+}
+
+#[test]
+fn axis_key_tick_label_rotation_to_string() {
+    assert_eq!(
+        AxisKey::XTickLabelRotation(45.0).to_string(),
+        "xticklabel style={rotate=45, anchor=east}"
+    );
+    assert_eq!(
+        AxisKey::XTickLabelRotation(-30.0).to_string(),
+        "xticklabel style={rotate=-30, anchor=west}"
+    );
+    assert_eq!(
+        AxisKey::XTickLabelRotation(0.0).to_string(),
+        "xticklabel style={rotate=0}"
+    );
+    assert_eq!(
+        AxisKey::YTickLabelRotation(90.0).to_string(),
+        "yticklabel style={rotate=90}"
+    );
+}
+
+#[test]
+fn axis_add_key_ticks() {
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::XTick(vec![0.0]));
+    axis.add_key(AxisKey::XTickLabelFormat(TickLabelFormat::default()));
+    axis.add_key(AxisKey::XTickLabelRotation(45.0));
+    axis.add_key(AxisKey::XTick(vec![1.0]));
+    assert_eq!(axis.keys.len(), 3);
+    assert_eq!(axis.keys[2].to_string(), "xtick={1}");
+}
+
+#[test]
+fn legend_positions_tested() {
+    let position = LegendPos::NorthEast;
+    match position {
+        LegendPos::SouthWest => (),
+        LegendPos::SouthEast => (),
+        LegendPos::NorthWest => (),
+        LegendPos::NorthEast => (),
+        LegendPos::OuterNorthEast => (),
+        LegendPos::OuterEast => (),
+        LegendPos::OuterSouthEast => (),
+    }
+}
+
+#[test]
+fn axis_key_legend_pos_to_string() {
+    assert_eq!(
+        AxisKey::LegendPos(LegendPos::SouthWest).to_string(),
+        "legend pos=south west"
+    );
+    assert_eq!(
+        AxisKey::LegendPos(LegendPos::SouthEast).to_string(),
+        "legend pos=south east"
+    );
+    assert_eq!(
+        AxisKey::LegendPos(LegendPos::NorthWest).to_string(),
+        "legend pos=north west"
+    );
+    assert_eq!(
+        AxisKey::LegendPos(LegendPos::NorthEast).to_string(),
+        "legend pos=north east"
+    );
+    assert_eq!(
+        AxisKey::LegendPos(LegendPos::OuterNorthEast).to_string(),
+        "legend pos=outer north east"
+    );
+    assert_eq!(
+        AxisKey::LegendPos(LegendPos::OuterEast).to_string(),
+        "legend style={at={(1.03,0.5)}, anchor=west}"
+    );
+    assert_eq!(
+        AxisKey::LegendPos(LegendPos::OuterSouthEast).to_string(),
+        "legend style={at={(1.03,0)}, anchor=south west}"
+    );
+}
+
+#[test]
+fn axis_key_legend_to_string() {
+    assert_eq!(AxisKey::LegendColumns(3).to_string(), "legend columns=3");
+    assert_eq!(
+        AxisKey::LegendStyle(String::from("draw=none")).to_string(),
+        "legend style={draw=none}"
+    );
+    assert_eq!(AxisKey::ReverseLegend.to_string(), "reverse legend");
+}
+
+#[test]
+fn grids_tested() {
+    let grid = Grid::Major;
+    match grid {
+        Grid::Major => (),
+        Grid::Minor => (),
+        Grid::Both => (),
+        Grid::None => (),
+    }
+}
+
+#[test]
+fn axis_lines_tested() {
+    let axis_lines = AxisLines::Box;
+    match axis_lines {
+        AxisLines::Box => (),
+        AxisLines::Left => (),
+        AxisLines::Middle => (),
+        AxisLines::Center => (),
+        AxisLines::Right => (),
+        AxisLines::None => (),
+    }
+}
+
+#[test]
+fn x_axis_lines_tested() {
+    let axis_line = XAxisLine::Box;
+    match axis_line {
+        XAxisLine::Box => (),
+        XAxisLine::Top => (),
+        XAxisLine::Middle => (),
+        XAxisLine::Bottom => (),
+        XAxisLine::None => (),
+    }
+}
+
+#[test]
+fn y_axis_lines_tested() {
+    let axis_line = YAxisLine::Box;
+    match axis_line {
+        YAxisLine::Box => (),
+        YAxisLine::Left => (),
+        YAxisLine::Middle => (),
+        YAxisLine::Right => (),
+        YAxisLine::None => (),
+    }
+}
+
+#[test]
+fn line_patterns_tested() {
+    let pattern = LinePattern::Solid;
+    match pattern {
+        LinePattern::Solid => (),
+        LinePattern::Dotted => (),
+        LinePattern::DenselyDotted => (),
+        LinePattern::LooselyDotted => (),
+        LinePattern::Dashed => (),
+        LinePattern::DenselyDashed => (),
+        LinePattern::LooselyDashed => (),
+        LinePattern::DashDotted => (),
+        LinePattern::DenselyDashDotted => (),
+        LinePattern::LooselyDashDotted => (),
+    }
+}
+
+#[test]
+fn line_thicknesses_tested() {
+    let thickness = LineThickness::Thin;
+    match thickness {
+        LineThickness::UltraThin => (),
+        LineThickness::VeryThin => (),
+        LineThickness::Thin => (),
+        LineThickness::Semithick => (),
+        LineThickness::Thick => (),
+        LineThickness::VeryThick => (),
+        LineThickness::UltraThick => (),
+    }
+}
+
+#[test]
+fn grid_to_string() {
+    assert_eq!(Grid::Major.to_string(), "major");
+    assert_eq!(Grid::Minor.to_string(), "minor");
+    assert_eq!(Grid::Both.to_string(), "both");
+    assert_eq!(Grid::None.to_string(), "none");
+}
+
+#[test]
+fn axis_lines_to_string() {
+    assert_eq!(AxisLines::Box.to_string(), "box");
+    assert_eq!(AxisLines::Left.to_string(), "left");
+    assert_eq!(AxisLines::Middle.to_string(), "middle");
+    assert_eq!(AxisLines::Center.to_string(), "center");
+    assert_eq!(AxisLines::Right.to_string(), "right");
+    assert_eq!(AxisLines::None.to_string(), "none");
+
+    assert_eq!(XAxisLine::Box.to_string(), "box");
+    assert_eq!(XAxisLine::Top.to_string(), "top");
+    assert_eq!(XAxisLine::Middle.to_string(), "middle");
+    assert_eq!(XAxisLine::Bottom.to_string(), "bottom");
+    assert_eq!(XAxisLine::None.to_string(), "none");
+
+    assert_eq!(YAxisLine::Box.to_string(), "box");
+    assert_eq!(YAxisLine::Left.to_string(), "left");
+    assert_eq!(YAxisLine::Middle.to_string(), "middle");
+    assert_eq!(YAxisLine::Right.to_string(), "right");
+    assert_eq!(YAxisLine::None.to_string(), "none");
+}
+
+#[test]
+fn line_pattern_to_string() {
+    assert_eq!(LinePattern::Solid.to_string(), "solid");
+    assert_eq!(LinePattern::Dotted.to_string(), "dotted");
+    assert_eq!(LinePattern::DenselyDotted.to_string(), "densely dotted");
+    assert_eq!(LinePattern::LooselyDotted.to_string(), "loosely dotted");
+    assert_eq!(LinePattern::Dashed.to_string(), "dashed");
+    assert_eq!(LinePattern::DenselyDashed.to_string(), "densely dashed");
+    assert_eq!(LinePattern::LooselyDashed.to_string(), "loosely dashed");
+    assert_eq!(LinePattern::DashDotted.to_string(), "dashdotted");
+    assert_eq!(
+        LinePattern::DenselyDashDotted.to_string(),
+        "densely dashdotted"
+    );
+    assert_eq!(
+        LinePattern::LooselyDashDotted.to_string(),
+        "loosely dashdotted"
+    );
+}
+
+#[test]
+fn line_thickness_to_string() {
+    assert_eq!(LineThickness::UltraThin.to_string(), "ultra thin");
+    assert_eq!(LineThickness::VeryThin.to_string(), "very thin");
+    assert_eq!(LineThickness::Thin.to_string(), "thin");
+    assert_eq!(LineThickness::Semithick.to_string(), "semithick");
+    assert_eq!(LineThickness::Thick.to_string(), "thick");
+    assert_eq!(LineThickness::VeryThick.to_string(), "very thick");
+    assert_eq!(LineThickness::UltraThick.to_string(), "ultra thick");
+}
+
+#[test]
+fn line_style_to_string() {
+    assert_eq!(LineStyle::default().to_string(), "");
+    let style = LineStyle {
+        pattern: Some(LinePattern::Dashed),
+        thickness: Some(LineThickness::Thick),
+        color: Some(Color::mix(NamedColor::Blue, 30.0, NamedColor::White)),
+        opacity: Some(0.5),
+    };
+    assert_eq!(
+        style.to_string(),
+        "dashed, thick, color=blue!30!white, opacity=0.5"
+    );
+}
+
+#[test]
+fn axis_key_grid_to_string() {
+    let style = LineStyle {
+        pattern: Some(LinePattern::Dotted),
+        ..Default::default()
+    };
+    assert_eq!(AxisKey::Grid(Grid::Both).to_string(), "grid=both");
+    assert_eq!(
+        AxisKey::GridStyle(style.clone()).to_string(),
+        "grid style={dotted}"
+    );
+    assert_eq!(
+        AxisKey::MajorGridStyle(style.clone()).to_string(),
+        "major grid style={dotted}"
+    );
+    assert_eq!(
+        AxisKey::MinorGridStyle(style).to_string(),
+        "minor grid style={dotted}"
+    );
+}
+
+#[test]
+fn axis_key_axis_lines_to_string() {
+    assert_eq!(
+        AxisKey::AxisLines(AxisLines::Middle).to_string(),
+        "axis lines=middle"
+    );
+    assert_eq!(
+        AxisKey::XAxisLine(XAxisLine::Bottom).to_string(),
+        "axis x line*=bottom"
+    );
+    assert_eq!(
+        AxisKey::YAxisLine(YAxisLine::Left).to_string(),
+        "axis y line*=left"
+    );
+    let style = LineStyle {
+        thickness: Some(LineThickness::Thin),
+        ..Default::default()
+    };
+    assert_eq!(
+        AxisKey::AxisLineStyle(style).to_string(),
+        "axis line style={thin}"
+    );
+    assert_eq!(AxisKey::AxisOnTop.to_string(), "axis on top");
+}
+
+#[test]
+fn axis_key_hide_axis_to_string() {
+    assert_eq!(AxisKey::HideAxis.to_string(), "hide axis");
+    assert_eq!(AxisKey::HideXAxis.to_string(), "hide x axis");
+    assert_eq!(AxisKey::HideYAxis.to_string(), "hide y axis");
+    assert_eq!(AxisKey::HideZAxis.to_string(), "hide z axis");
+}
+
+#[test]
+fn axis_key_enlarge_limits_to_string() {
+    assert_eq!(
+        AxisKey::EnlargeLimits(false).to_string(),
+        "enlargelimits=false"
+    );
+    assert_eq!(
+        AxisKey::EnlargeLimits(true).to_string(),
+        "enlargelimits=true"
+    );
+}
+
+#[test]
+fn axis_key_reverse_axis_to_string() {
+    assert_eq!(AxisKey::ReverseXAxis.to_string(), "x dir=reverse");
+    assert_eq!(AxisKey::ReverseYAxis.to_string(), "y dir=reverse");
+    assert_eq!(AxisKey::ReverseZAxis.to_string(), "z dir=reverse");
+}
+
+#[test]
+fn aspects_tested() {
+    let aspect = Aspect::Equal;
+    match aspect {
+        Aspect::Equal => (),
+        Aspect::EqualImage => (),
+        Aspect::UnitVectorRatio(_) => (),
+    }
+}
+
+#[test]
+fn aspect_to_string() {
+    assert_eq!(Aspect::Equal.to_string(), "axis equal");
+    assert_eq!(Aspect::EqualImage.to_string(), "axis equal image");
+    assert_eq!(
+        Aspect::UnitVectorRatio(vec![1.0, 2.5]).to_string(),
+        "unit vector ratio={1 2.5}"
+    );
+    assert_eq!(
+        Aspect::UnitVectorRatio(vec![1.0, f64::NAN]).to_string(),
+        "unit vector ratio={1 nan}"
+    );
+}
+
+#[test]
+fn axis_key_size_to_string() {
+    assert_eq!(AxisKey::Width(Length::cm(8.0)).to_string(), "width=8cm");
+    assert_eq!(
+        AxisKey::Height(Length::line_width(0.5)).to_string(),
+        "height=0.5\\linewidth"
+    );
+    assert_eq!(AxisKey::ScaleOnlyAxis.to_string(), "scale only axis");
+    assert_eq!(
+        AxisKey::Aspect(Aspect::EqualImage).to_string(),
+        "axis equal image"
+    );
+}
+
+#[test]
+fn axis_set_size() {
+    let mut axis = Axis::new();
+    axis.set_size(Length::cm(1.0), Length::cm(2.0));
+    axis.set_size(ColumnPreset::NatureSingle, Length::mm(60.0));
+    assert_eq!(axis.keys.len(), 2);
+    assert_eq!(axis.keys[0].to_string(), "width=89mm");
+    assert_eq!(axis.keys[1].to_string(), "height=60mm");
+}
+
+#[test]
+fn stacks_tested() {
+    let stack = Stack::Y;
+    match stack {
+        Stack::YBar => (),
+        Stack::XBar => (),
+        Stack::Y => (),
+        Stack::X => (),
+        Stack::AreaY => (),
+        Stack::AreaX => (),
+    }
+}
+
+#[test]
+fn stack_to_string() {
+    assert_eq!(Stack::YBar.to_string(), "ybar stacked");
+    assert_eq!(Stack::XBar.to_string(), "xbar stacked");
+    assert_eq!(Stack::Y.to_string(), "stack plots=y");
+    assert_eq!(Stack::X.to_string(), "stack plots=x");
+    assert_eq!(Stack::AreaY.to_string(), "stack plots=y, area style");
+    assert_eq!(Stack::AreaX.to_string(), "stack plots=x, area style");
+}
+
+#[test]
+fn axis_key_stack_to_string() {
+    assert_eq!(AxisKey::Stack(Stack::YBar).to_string(), "ybar stacked");
 }
 
 #[test]
@@ -36,6 +632,91 @@ fn axis_key_y_label_to_string() {
     );
 }
 
+#[test]
+fn axis_key_z_label_to_string() {
+    assert_eq!(
+        AxisKey::ZLabel(String::from("Random Label")).to_string(),
+        "zlabel={Random Label}"
+    );
+}
+
+#[test]
+fn axis_key_z_mode_to_string() {
+    assert_eq!(AxisKey::ZMode(Scale::Log).to_string(), "zmode=log");
+    assert_eq!(AxisKey::ZMode(Scale::Normal).to_string(), "zmode=normal");
+}
+
+#[test]
+fn axis_key_view_to_string() {
+    assert_eq!(
+        AxisKey::View {
+            azimuth: 25.0,
+            elevation: -30.5
+        }
+        .to_string(),
+        "view={25}{-30.5}"
+    );
+}
+
+#[test]
+fn colormap_to_string() {
+    assert_eq!(
+        Colormap::Custom(String::from("colormap name=mymap")).to_string(),
+        "colormap name=mymap"
+    );
+    assert_eq!(Colormap::Hot.to_string(), "colormap/hot");
+    assert_eq!(Colormap::Hot2.to_string(), "colormap/hot2");
+    assert_eq!(Colormap::Jet.to_string(), "colormap/jet");
+    assert_eq!(Colormap::Blackwhite.to_string(), "colormap/blackwhite");
+    assert_eq!(Colormap::Bluered.to_string(), "colormap/bluered");
+    assert_eq!(Colormap::Cool.to_string(), "colormap/cool");
+    assert_eq!(Colormap::Greenyellow.to_string(), "colormap/greenyellow");
+    assert_eq!(Colormap::Redyellow.to_string(), "colormap/redyellow");
+    assert_eq!(Colormap::Violet.to_string(), "colormap/violet");
+    assert_eq!(Colormap::Viridis.to_string(), "colormap/viridis");
+}
+
+#[test]
+fn colorbar_to_string() {
+    assert_eq!(Colorbar::Right.to_string(), "colorbar");
+    assert_eq!(Colorbar::Left.to_string(), "colorbar left");
+    assert_eq!(Colorbar::Horizontal.to_string(), "colorbar horizontal");
+}
+
+#[test]
+fn axis_key_colormap_to_string() {
+    assert_eq!(
+        AxisKey::Colormap(Colormap::Viridis).to_string(),
+        "colormap/viridis"
+    );
+}
+
+#[test]
+fn axis_key_colorbar_to_string() {
+    assert_eq!(
+        AxisKey::Colorbar(Colorbar::Horizontal).to_string(),
+        "colorbar horizontal"
+    );
+}
+
+#[test]
+fn axis_key_point_meta_min_to_string() {
+    assert_eq!(AxisKey::PointMetaMin(-1.0).to_string(), "point meta min=-1");
+    assert_eq!(
+        AxisKey::PointMetaMin(f64::NEG_INFINITY).to_string(),
+        "point meta min=-inf"
+    );
+}
+
+#[test]
+fn axis_key_point_meta_max_to_string() {
+    assert_eq!(AxisKey::PointMetaMax(0.5).to_string(), "point meta max=0.5");
+    assert_eq!(
+        AxisKey::PointMetaMax(f64::NAN).to_string(),
+        "point meta max=nan"
+    );
+}
+
 #[test]
 fn axis_key_x_label_to_string() {
     assert_eq!(
@@ -165,6 +846,7 @@ fn axis_standalone_string() {
     assert_eq!(
         r#"\documentclass{standalone}
 \usepackage{pgfplots}
+\pgfplotsset{compat=1.16}
 \begin{document}
 \begin{tikzpicture}
 \begin{axis}
@@ -188,7 +870,7 @@ fn axis_to_string() {
 
     axis.keys.clear();
     let mut plot = Plot2D::new();
-    axis.plots.push(plot.clone());
+    axis.plots.push(plot.clone().into());
     assert_eq!(
         axis.to_string(),
         "\\begin{axis}\n\t\\addplot[] coordinates {\n\t};\n\\end{axis}"
@@ -200,6 +882,304 @@ fn axis_to_string() {
     plot.coordinates.push((1.0, -1.0, None, None).into());
     plot.add_key(PlotKey::XError(ErrorCharacter::Absolute));
     plot.add_key(PlotKey::XErrorDirection(ErrorDirection::Both));
-    axis.plots.push(plot);
+    axis.plots.push(plot.into());
     assert_eq!(axis.to_string(), "\\begin{axis}[\n\tymode=log,\n\txmode=log,\n]\n\t\\addplot[] coordinates {\n\t};\n\t\\addplot[\n\t\terror bars/x explicit,\n\t\terror bars/x dir=both,\n\t] coordinates {\n\t\t(1,-1)\t+- (0,5)\n\t\t(1,-1)\n\t};\n\\end{axis}");
 }
+
+#[test]
+fn axis_write_standalone() {
+    let mut axis = Axis::new();
+    axis.set_x_label("x");
+    axis.plots.push(Plot2D::new().into());
+
+    let mut buffer = Vec::new();
+    axis.write_standalone(&mut buffer).unwrap();
+    assert_eq!(axis.standalone_string(), String::from_utf8(buffer).unwrap());
+}
+
+#[test]
+fn axis_set_z_label() {
+    let mut axis = Axis::new();
+    axis.set_z_label("Something");
+    assert_eq!(axis.keys.len(), 1);
+    assert!(matches!(axis.keys[0], AxisKey::ZLabel(_)));
+}
+
+#[test]
+fn axis_mixed_plots_to_string() {
+    let mut axis = Axis::new();
+    let mut plot_2d = Plot2D::new();
+    plot_2d.coordinates.push((1.0, 2.0).into());
+    let mut plot_3d = Plot3D::new();
+    plot_3d.coordinates.push((1.0, 2.0, 3.0).into());
+    axis.plots.push(plot_2d.into());
+    axis.plots.push(plot_3d.into());
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot[] coordinates {\n\t\t(1,2)\n\t};\n\t\\addplot3[] coordinates {\n\t\t(1,2,3)\n\t};\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_fill_between() {
+    let mut axis = Axis::new();
+    let mut upper = Plot2D::new();
+    upper.add_key(PlotKey::NamePath(String::from("upper")));
+    axis.plots.push(Plot2D::new().into());
+    axis.plots.push(upper.into());
+
+    let fill = axis.fill_between(0, 1);
+    fill.soft_clip = Some((1.0, 2.5));
+    assert_eq!(axis.plots.len(), 3);
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot[\n\t\tname path=plot0,\n\t] coordinates {\n\t};\n\t\\addplot[\n\t\tname path=upper,\n\t] coordinates {\n\t};\n\t\\addplot[] fill between[of=plot0 and upper, soft clip={domain=1:2.5}];\n\\end{axis}"
+    );
+}
+
+#[test]
+#[should_panic]
+fn axis_fill_between_not_plot_2d() {
+    let mut axis = Axis::new();
+    axis.plots.push(Plot2D::new().into());
+    axis.plots.push(Plot3D::new().into());
+    axis.fill_between(0, 1);
+}
+
+#[test]
+#[should_panic]
+fn axis_fill_between_out_of_bounds() {
+    let mut axis = Axis::new();
+    axis.plots.push(Plot2D::new().into());
+    axis.fill_between(0, 1);
+}
+
+#[test]
+fn axis_legend_to_string() {
+    let mut axis = Axis::new();
+    let mut first = Plot2D::new();
+    first.legend = Some(String::from("$x^2$"));
+    axis.plots.push(first.into());
+    axis.plots.push(Plot2D::new().into());
+    axis.plots.push(Plot3D::new().into());
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addplot[] coordinates {\n\t};\n\t\\addlegendentry{$x^2$}\n\t\\addplot[] coordinates {\n\t};\n\t\\addplot3[] coordinates {\n\t};\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_legend_order_to_string() {
+    let mut axis = Axis::new();
+    let mut first = Plot2D::new();
+    first.add_key(PlotKey::Custom(String::from("red")));
+    first.add_key(PlotKey::Type2D(Type2D::OnlyMarks));
+    first.legend = Some(String::from("first"));
+    let mut second = Plot2D::new();
+    second.legend = Some(String::from("second"));
+    axis.plots.push(first.into());
+    axis.plots.push(second.into());
+
+    axis.legend_order = Some(vec![1, 0, 2]);
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}\n\t\\addlegendimage{}\n\t\\addlegendentry{second}\n\t\\addlegendimage{red, only marks}\n\t\\addlegendentry{first}\n\t\\addplot[\n\t\tred,\n\t\tonly marks,\n\t] coordinates {\n\t};\n\t\\addplot[] coordinates {\n\t};\n\\end{axis}"
+    );
+
+    axis.legend_order = Some(Vec::new());
+    assert!(!axis.to_string().contains("addlegend"));
+}
+
+#[test]
+fn axis_legend_forget_plot() {
+    let mut axis = Axis::new();
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::ForgetPlot);
+    plot.legend = Some(String::from("hidden"));
+    axis.plots.push(plot.into());
+    assert!(!axis.to_string().contains("addlegend"));
+
+    axis.legend_order = Some(vec![0]);
+    assert!(!axis.to_string().contains("addlegend"));
+}
+
+#[test]
+fn axis_legend_error_band() {
+    let mut axis = Axis::new();
+    let mut band = ErrorBand::from_deviation(vec![0.0], vec![1.0], &[0.5]);
+    band.legend = Some(String::from("mean"));
+    axis.plots.push(band.into());
+    assert!(axis
+        .to_string()
+        .ends_with("\t};\n\t\\addlegendentry{mean}\n\\end{axis}"));
+
+    axis.legend_order = Some(vec![0]);
+    assert!(!axis.to_string().contains("addlegend"));
+}
+
+/// Return a plot with the given `(x, y)` coordinates.
+fn plot_2d(coordinates: &[(f64, f64)]) -> Plot2D {
+    let mut plot = Plot2D::new();
+    plot.coordinates = coordinates.iter().map(|&c| c.into()).collect();
+    plot
+}
+
+#[test]
+fn axis_stack() {
+    let mut axis = Axis::new();
+    axis.plots.push(plot_2d(&[(0.0, 1.0), (1.0, 2.0)]).into());
+    axis.plots.push(plot_2d(&[(0.0, 3.0), (1.0, 4.0)]).into());
+    assert_eq!(axis.stack(Stack::YBar), Ok(()));
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(axis.keys[0].to_string(), "ybar stacked");
+    assert!(axis.plots.iter().all(|plot| match plot {
+        Plot::Plot2D(plot) => !plot.closed_cycle,
+        _ => false,
+    }));
+
+    assert_eq!(
+        axis.stack(Stack::X),
+        Err(StackError::MismatchedCoordinates { index: 1 })
+    );
+    assert_eq!(axis.keys[0].to_string(), "ybar stacked");
+}
+
+#[test]
+fn axis_stack_area() {
+    let mut axis = Axis::new();
+    axis.plots.push(plot_2d(&[(0.0, 1.0), (1.0, 2.0)]).into());
+    axis.plots.push(plot_2d(&[(0.0, 3.0), (1.0, 4.0)]).into());
+    axis.stack(Stack::AreaY).unwrap();
+    assert_eq!(
+        axis.to_string(),
+        "\\begin{axis}[\n\tstack plots=y, area style,\n]\n\t\\addplot[] coordinates {\n\t\t(0,1)\n\t\t(1,2)\n\t} \\closedcycle;\n\t\\addplot[] coordinates {\n\t\t(0,3)\n\t\t(1,4)\n\t} \\closedcycle;\n\\end{axis}"
+    );
+}
+
+#[test]
+fn axis_stack_again() {
+    let mut axis = Axis::new();
+    axis.plots.push(plot_2d(&[(0.0, 1.0), (1.0, 2.0)]).into());
+    axis.plots.push(plot_2d(&[(0.0, 3.0), (1.0, 4.0)]).into());
+    axis.stack(Stack::AreaY).unwrap();
+    axis.stack(Stack::Y).unwrap();
+    assert_eq!(axis.keys.len(), 1);
+    assert_eq!(axis.keys[0].to_string(), "stack plots=y");
+    assert!(axis.plots.iter().all(|plot| match plot {
+        Plot::Plot2D(plot) => !plot.closed_cycle,
+        _ => false,
+    }));
+
+    // Cycles closed by the user are kept.
+    if let Plot::Plot2D(plot) = &mut axis.plots[0] {
+        plot.closed_cycle = true;
+    }
+    axis.stack(Stack::X).unwrap_err();
+    axis.stack(Stack::Y).unwrap();
+    assert!(matches!(&axis.plots[0], Plot::Plot2D(plot) if plot.closed_cycle));
+}
+
+#[test]
+fn axis_stack_bars_type_2d() {
+    let mut axis = Axis::new();
+    axis.plots.push(plot_2d(&[(0.0, 1.0), (1.0, 2.0)]).into());
+    let mut plot = plot_2d(&[(0.0, 3.0), (1.0, 4.0)]);
+    plot.add_key(PlotKey::Type2D(Type2D::YBar {
+        bar_width: Length::pt(10.0),
+        bar_shift: Length::pt(5.0),
+    }));
+    axis.plots.push(plot.into());
+    assert_eq!(
+        axis.stack(Stack::YBar),
+        Err(StackError::Type2D { index: 1 })
+    );
+    assert!(axis.keys.is_empty());
+    assert_eq!(axis.stack(Stack::Y), Ok(()));
+}
+
+#[test]
+fn axis_stack_errors() {
+    let mut axis = Axis::new();
+    axis.plots.push(plot_2d(&[(0.0, 1.0), (1.0, 2.0)]).into());
+    axis.plots.push(plot_2d(&[(0.0, 3.0)]).into());
+    assert_eq!(
+        axis.stack(Stack::Y),
+        Err(StackError::MismatchedCoordinates { index: 1 })
+    );
+    axis.plots[1] = plot_2d(&[(0.0, 3.0), (2.0, 4.0)]).into();
+    assert_eq!(
+        axis.stack(Stack::AreaY),
+        Err(StackError::MismatchedCoordinates { index: 1 })
+    );
+    axis.plots[1] = Plot3D::new().into();
+    assert_eq!(
+        axis.stack(Stack::Y),
+        Err(StackError::NotPlot2D { index: 1 })
+    );
+    assert!(axis.keys.is_empty());
+    assert!(axis.plots.iter().all(|plot| match plot {
+        Plot::Plot2D(plot) => !plot.closed_cycle,
+        _ => true,
+    }));
+}
+
+#[test]
+fn stack_error_to_string() {
+    assert_eq!(
+        StackError::NotPlot2D { index: 2 }.to_string(),
+        "plot 2 is not a two-dimensional plot"
+    );
+    assert_eq!(
+        StackError::MismatchedCoordinates { index: 1 }.to_string(),
+        "plot 1 has different coordinates than the first plot"
+    );
+    assert_eq!(
+        StackError::Type2D { index: 0 }.to_string(),
+        "plot 0 has its own type of plot"
+    );
+}
+
+#[test]
+fn axis_group_bars() {
+    let mut axis = Axis::new();
+    for type_2d in [
+        Type2D::YBar {
+            bar_width: Length::axis(1.0),
+            bar_shift: Length::axis(5.0),
+        },
+        Type2D::SharpPlot,
+        Type2D::XBar {
+            bar_width: Length::axis(1.0),
+            bar_shift: Length::axis(5.0),
+        },
+        Type2D::YBar {
+            bar_width: Length::axis(1.0),
+            bar_shift: Length::axis(5.0),
+        },
+    ] {
+        let mut plot = Plot2D::new();
+        plot.add_key(PlotKey::Type2D(type_2d));
+        axis.plots.push(plot.into());
+    }
+    axis.plots.push(Plot2D::new().into());
+    axis.group_bars(Length::axis(10.0));
+
+    let keys: Vec<String> = axis
+        .plots
+        .iter()
+        .map(|plot| match plot {
+            Plot::Plot2D(plot) => plot.type_2d().map(|t| t.to_string()).unwrap_or_default(),
+            _ => unreachable!(),
+        })
+        .collect();
+    assert_eq!(
+        keys,
+        vec![
+            "ybar, bar width=10, bar shift=-10",
+            "sharp plot",
+            "xbar, bar width=10, bar shift=0",
+            "ybar, bar width=10, bar shift=10",
+            "",
+        ]
+    );
+}
@@ -0,0 +1,149 @@
+use std::fmt;
+
+/// Color written into LaTeX code e.g. `red`, `blue!30!white` or an RGB color.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::color::{Color, NamedColor};
+///
+/// assert_eq!(Color::from(NamedColor::Red).to_string(), "red");
+/// assert_eq!(
+///     Color::mix(NamedColor::Blue, 30.0, NamedColor::White).to_string(),
+///     "blue!30!white"
+/// );
+/// assert_eq!(
+///     Color::Rgb(255, 128, 0).to_string(),
+///     "{rgb,255:red,255;green,128;blue,0}"
+/// );
+/// assert_eq!(Color::html(0xFF8000), Color::Rgb(255, 128, 0));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Color {
+    /// Custom color written verbatim e.g. a color defined with
+    /// `\definecolor` in the preamble, or a more complex `xcolor` expression.
+    Custom(String),
+    /// Color predefined by the `xcolor` package.
+    Named(NamedColor),
+    /// Color with red, green and blue components between `0` and `255`.
+    Rgb(u8, u8, u8),
+    /// Mix of `percent` percent of the `first` color and the rest of the
+    /// `second` color, as written by `xcolor` e.g. `blue!30!white`.
+    Mix {
+        first: NamedColor,
+        percent: f64,
+        second: NamedColor,
+    },
+}
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Color::Custom(color) => write!(f, "{color}"),
+            Color::Named(color) => write!(f, "{color}"),
+            // Braced because the expression contains commas.
+            Color::Rgb(red, green, blue) => {
+                write!(f, "{{rgb,255:red,{red};green,{green};blue,{blue}}}")
+            }
+            Color::Mix {
+                first,
+                percent,
+                second,
+            } => write!(f, "{first}!{percent}!{second}"),
+        }
+    }
+}
+impl From<NamedColor> for Color {
+    fn from(color: NamedColor) -> Self {
+        Color::Named(color)
+    }
+}
+
+impl Color {
+    /// Creates a mix of `percent` percent of the `first` color and the rest of
+    /// the `second` color. The percentage is clamped between `0` and `100`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::color::{Color, NamedColor};
+    ///
+    /// let color = Color::mix(NamedColor::Red, 150.0, NamedColor::Black);
+    /// assert_eq!(color.to_string(), "red!100!black");
+    /// ```
+    pub fn mix(first: NamedColor, percent: f64, second: NamedColor) -> Self {
+        Color::Mix {
+            first,
+            percent: percent.clamp(0.0, 100.0),
+            second,
+        }
+    }
+    /// Creates a color from its HTML hex code e.g. `0xFF8000` for `#FF8000`.
+    /// Bits above the lowest 24 are ignored.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::color::Color;
+    ///
+    /// assert_eq!(Color::html(0x1F77B4), Color::Rgb(31, 119, 180));
+    /// ```
+    pub fn html(hex: u32) -> Self {
+        let [_, red, green, blue] = hex.to_be_bytes();
+        Color::Rgb(red, green, blue)
+    }
+}
+
+/// Colors predefined by the `xcolor` package, which is always loaded by
+/// Ti*k*Z.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NamedColor {
+    Black,
+    Blue,
+    Brown,
+    Cyan,
+    DarkGray,
+    Gray,
+    Green,
+    LightGray,
+    Lime,
+    Magenta,
+    Olive,
+    Orange,
+    Pink,
+    Purple,
+    Red,
+    Teal,
+    Violet,
+    White,
+    Yellow,
+}
+impl fmt::Display for NamedColor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NamedColor::Black => write!(f, "black"),
+            NamedColor::Blue => write!(f, "blue"),
+            NamedColor::Brown => write!(f, "brown"),
+            NamedColor::Cyan => write!(f, "cyan"),
+            NamedColor::DarkGray => write!(f, "darkgray"),
+            NamedColor::Gray => write!(f, "gray"),
+            NamedColor::Green => write!(f, "green"),
+            NamedColor::LightGray => write!(f, "lightgray"),
+            NamedColor::Lime => write!(f, "lime"),
+            NamedColor::Magenta => write!(f, "magenta"),
+            NamedColor::Olive => write!(f, "olive"),
+            NamedColor::Orange => write!(f, "orange"),
+            NamedColor::Pink => write!(f, "pink"),
+            NamedColor::Purple => write!(f, "purple"),
+            NamedColor::Red => write!(f, "red"),
+            NamedColor::Teal => write!(f, "teal"),
+            NamedColor::Violet => write!(f, "violet"),
+            NamedColor::White => write!(f, "white"),
+            NamedColor::Yellow => write!(f, "yellow"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
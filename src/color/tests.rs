@@ -0,0 +1,118 @@
+use super::*;
+
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn colors_tested() {
+    let color = Color::Custom(String::from(""));
+    match color {
+        Color::Custom(_) => (),
+        Color::Named(_) => (),
+        Color::Rgb(_, _, _) => (),
+        Color::Mix {
+            first: _,
+            percent: _,
+            second: _,
+        } => (),
+    }
+}
+
+#[test]
+fn named_colors_tested() {
+    let color = NamedColor::Black;
+    match color {
+        NamedColor::Black => (),
+        NamedColor::Blue => (),
+        NamedColor::Brown => (),
+        NamedColor::Cyan => (),
+        NamedColor::DarkGray => (),
+        NamedColor::Gray => (),
+        NamedColor::Green => (),
+        NamedColor::LightGray => (),
+        NamedColor::Lime => (),
+        NamedColor::Magenta => (),
+        NamedColor::Olive => (),
+        NamedColor::Orange => (),
+        NamedColor::Pink => (),
+        NamedColor::Purple => (),
+        NamedColor::Red => (),
+        NamedColor::Teal => (),
+        NamedColor::Violet => (),
+        NamedColor::White => (),
+        NamedColor::Yellow => (),
+    }
+}
+
+#[test]
+fn color_custom_to_string() {
+    assert_eq!(
+        Color::Custom(String::from("red!30!blue!50!white")).to_string(),
+        "red!30!blue!50!white"
+    );
+}
+
+#[test]
+fn color_named_to_string() {
+    assert_eq!(Color::Named(NamedColor::Teal).to_string(), "teal");
+    assert_eq!(Color::from(NamedColor::Orange).to_string(), "orange");
+}
+
+#[test]
+fn color_rgb_to_string() {
+    assert_eq!(
+        Color::Rgb(0, 10, 255).to_string(),
+        "{rgb,255:red,0;green,10;blue,255}"
+    );
+}
+
+#[test]
+fn color_mix_to_string() {
+    assert_eq!(
+        Color::mix(NamedColor::Blue, 30.0, NamedColor::White).to_string(),
+        "blue!30!white"
+    );
+    assert_eq!(
+        Color::mix(NamedColor::Red, 12.5, NamedColor::Black).to_string(),
+        "red!12.5!black"
+    );
+    assert_eq!(
+        Color::mix(NamedColor::Red, -5.0, NamedColor::Black).to_string(),
+        "red!0!black"
+    );
+}
+
+#[test]
+fn color_html() {
+    assert_eq!(Color::html(0x000000), Color::Rgb(0, 0, 0));
+    assert_eq!(Color::html(0xFFFFFF), Color::Rgb(255, 255, 255));
+    assert_eq!(Color::html(0x123456), Color::Rgb(0x12, 0x34, 0x56));
+    assert_eq!(Color::html(0xAB123456), Color::Rgb(0x12, 0x34, 0x56));
+}
+
+#[test]
+fn named_color_to_string() {
+    assert_eq!(NamedColor::Black.to_string(), "black");
+    assert_eq!(NamedColor::Blue.to_string(), "blue");
+    assert_eq!(NamedColor::Brown.to_string(), "brown");
+    assert_eq!(NamedColor::Cyan.to_string(), "cyan");
+    assert_eq!(NamedColor::DarkGray.to_string(), "darkgray");
+    assert_eq!(NamedColor::Gray.to_string(), "gray");
+    assert_eq!(NamedColor::Green.to_string(), "green");
+    assert_eq!(NamedColor::LightGray.to_string(), "lightgray");
+    assert_eq!(NamedColor::Lime.to_string(), "lime");
+    assert_eq!(NamedColor::Magenta.to_string(), "magenta");
+    assert_eq!(NamedColor::Olive.to_string(), "olive");
+    assert_eq!(NamedColor::Orange.to_string(), "orange");
+    assert_eq!(NamedColor::Pink.to_string(), "pink");
+    assert_eq!(NamedColor::Purple.to_string(), "purple");
+    assert_eq!(NamedColor::Red.to_string(), "red");
+    assert_eq!(NamedColor::Teal.to_string(), "teal");
+    assert_eq!(NamedColor::Violet.to_string(), "violet");
+    assert_eq!(NamedColor::White.to_string(), "white");
+    assert_eq!(NamedColor::Yellow.to_string(), "yellow");
+}
@@ -0,0 +1,486 @@
+use crate::ShowPdfError;
+use std::{
+    error::Error,
+    fmt,
+    fs::File,
+    io::{self, BufWriter},
+    path::Path,
+    process::{Command, ExitStatus, Stdio},
+};
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{axis::plot::contour::ContourMethod, Picture};
+
+/// Name (without extension) of the files written in the temporary directory
+/// used to compile a figure.
+const JOB_NAME: &str = "figure";
+/// Maximum number of characters per line in a LaTeX log file. Longer lines are
+/// hard-wrapped by the engine.
+const MAX_LOG_LINE: usize = 79;
+
+/// Details of a failed LaTeX compilation.
+///
+/// Besides the underlying error (available through [`Error::source`]), this
+/// keeps the log written by the LaTeX engine and the first error reported in
+/// it.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pgfplots::{axis::Axis, compiler::Engine, ShowPdfError};
+///
+/// let axis = Axis::new();
+/// if let Err(ShowPdfError::Compile(error)) = axis.to_pdf_bytes_with(&Engine::PdfLatex) {
+///     if let Some(latex_error) = error.latex_error() {
+///         eprintln!("{latex_error}");
+///     }
+/// }
+/// ```
+#[derive(Debug)]
+pub struct CompileError {
+    log: Option<String>,
+    latex_error: Option<LatexError>,
+    source: Option<Box<dyn Error + Send + Sync>>,
+}
+
+impl fmt::Display for CompileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.latex_error, &self.source) {
+            (Some(latex_error), _) => write!(f, "{latex_error}"),
+            (None, Some(source)) => write!(f, "{source}"),
+            (None, None) => write!(f, "LaTeX compilation failed"),
+        }
+    }
+}
+
+impl Error for CompileError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match &self.source {
+            Some(source) => Some(source.as_ref()),
+            None => None,
+        }
+    }
+}
+
+impl CompileError {
+    /// Create a new compilation error from the log written by the LaTeX
+    /// engine (if any) and the underlying error that caused the failure (if
+    /// any). The first error reported in the log is parsed automatically.
+    ///
+    /// This is only useful if you are implementing your own [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::compiler::CompileError;
+    ///
+    /// let log = String::from("! Undefined control sequence.\nl.5 \\foo");
+    /// let error = CompileError::new(Some(log), None);
+    ///
+    /// let latex_error = error.latex_error().unwrap();
+    /// assert_eq!(latex_error.message, "Undefined control sequence.");
+    /// assert_eq!(latex_error.line, Some(5));
+    /// ```
+    pub fn new(log: Option<String>, source: Option<Box<dyn Error + Send + Sync>>) -> Self {
+        let latex_error = log.as_deref().and_then(LatexError::parse);
+        CompileError {
+            log,
+            latex_error,
+            source,
+        }
+    }
+    /// Return the complete log written by the LaTeX engine, if available.
+    pub fn log(&self) -> Option<&str> {
+        self.log.as_deref()
+    }
+    /// Return the first error reported by LaTeX in the log, if any.
+    pub fn latex_error(&self) -> Option<&LatexError> {
+        self.latex_error.as_ref()
+    }
+}
+
+/// Error reported by LaTeX in the log of a failed compilation.
+///
+/// These are the log entries that start with `!` e.g. `! LaTeX Error: ...` or
+/// `! Package pgfkeys Error: ...`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LatexError {
+    /// Error message (without the leading `!`).
+    pub message: String,
+    /// Line at which the error was found. This is the line number (starting at
+    /// 1) in the LaTeX code returned by e.g. [`Picture::standalone_string`].
+    pub line: Option<usize>,
+}
+
+impl fmt::Display for LatexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)?;
+        if let Some(line) = self.line {
+            write!(f, " on line {line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl LatexError {
+    /// Find the first error in a LaTeX log.
+    fn parse(log: &str) -> Option<Self> {
+        let mut lines = log.lines().skip_while(|line| !line.starts_with("! "));
+
+        let first = lines.next()?;
+        let mut message = String::from(first.trim_start_matches("! "));
+        // Long messages are hard-wrapped; a line with the maximum length is
+        // continued in the next one.
+        let mut last_length = first.chars().count();
+        let lines = lines.skip_while(|line| {
+            let wrapped = last_length == MAX_LOG_LINE;
+            if wrapped {
+                message.push_str(line);
+                last_length = line.chars().count();
+            }
+            wrapped
+        });
+
+        // The context of the error is given as `l.<number> <code>`.
+        // Stop at the next error, which has its own context.
+        let line = lines
+            .take_while(|line| !line.starts_with("! "))
+            .find_map(|line| {
+                let line = line.strip_prefix("l.")?;
+                let end = line
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(line.len());
+                line[..end].parse().ok()
+            });
+
+        Some(LatexError { message, line })
+    }
+}
+
+/// Backend used to compile the LaTeX code of a figure into a PDF document.
+///
+/// This trait is implemented by [`Engine`] (LaTeX engines installed in your
+/// system) and, with the `inclusive` feature, by [`Tectonic`]. Implement it
+/// yourself to compile figures any other way e.g. on a remote server.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pgfplots::{compiler::Engine, Picture};
+///
+/// let picture = Picture::new();
+/// let pdf_data = picture.to_pdf_bytes_with(&Engine::PdfLatex).unwrap();
+/// ```
+pub trait Compiler {
+    /// Compile a complete LaTeX document and return the raw bytes of the
+    /// resulting PDF.
+    ///
+    /// The document (e.g. the output of [`Picture::write_standalone`]) is
+    /// streamed into any writer passed to `write_source`, so it doesn't have
+    /// to be kept in memory as a whole.
+    fn compile(
+        &self,
+        write_source: &dyn Fn(&mut dyn io::Write) -> io::Result<()>,
+    ) -> Result<Vec<u8>, ShowPdfError>;
+}
+
+/// LaTeX engine installed in your system.
+///
+/// The LaTeX code is written to a temporary directory, and the corresponding
+/// program is executed inside it. The program has to be available in your
+/// `PATH`.
+#[derive(Clone, Copy, Debug)]
+#[non_exhaustive]
+pub enum Engine {
+    /// Compile with `pdflatex`.
+    PdfLatex,
+    /// Compile with `lualatex`.
+    LuaLatex,
+    /// Compile with `xelatex`.
+    XeLatex,
+    /// Compile with `latexmk` using `pdflatex` as the underlying engine.
+    Latexmk,
+}
+
+impl Engine {
+    fn program(&self) -> &'static str {
+        match self {
+            Engine::PdfLatex => "pdflatex",
+            Engine::LuaLatex => "lualatex",
+            Engine::XeLatex => "xelatex",
+            Engine::Latexmk => "latexmk",
+        }
+    }
+    fn args(&self) -> Vec<String> {
+        let mut args = Vec::new();
+        if let Engine::Latexmk = self {
+            args.push(String::from("-pdf"));
+        }
+        args.push(String::from("-interaction=nonstopmode"));
+        args.push(String::from("-halt-on-error"));
+        args.push(format!("-jobname={JOB_NAME}"));
+        args.push(format!("{JOB_NAME}.tex"));
+        args
+    }
+}
+
+impl Compiler for Engine {
+    fn compile(
+        &self,
+        write_source: &dyn Fn(&mut dyn io::Write) -> io::Result<()>,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        self.compile_with_args(&self.args(), write_source)
+    }
+}
+
+impl Engine {
+    fn compile_with_args(
+        &self,
+        args: &[String],
+        write_source: &dyn Fn(&mut dyn io::Write) -> io::Result<()>,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        let directory = tempfile::tempdir()?;
+        let file = File::create(directory.path().join(format!("{JOB_NAME}.tex")))?;
+        write_source(&mut BufWriter::new(file))?;
+
+        let status = run(self.program(), args, directory.path())
+            .map_err(|error| CompileError::new(None, Some(Box::new(error))))?;
+        if !status.success() {
+            // The log is written with the encoding of the input file, so it is
+            // not guaranteed to be valid UTF-8.
+            let log = std::fs::read(directory.path().join(format!("{JOB_NAME}.log")))
+                .ok()
+                .map(|log| String::from_utf8_lossy(&log).into_owned());
+            let source = format!("`{}` exited with {status}", self.program());
+            return Err(CompileError::new(log, Some(source.into())).into());
+        }
+
+        Ok(std::fs::read(
+            directory.path().join(format!("{JOB_NAME}.pdf")),
+        )?)
+    }
+}
+
+/// [`Engine`] that runs with `-shell-escape`, which allows the LaTeX code to
+/// execute external programs e.g. `gnuplot` for [`ContourMethod::Gnuplot`].
+/// Only use it to compile LaTeX code that you trust.
+///
+/// # Examples
+///
+/// ```no_run
+/// use pgfplots::{
+///     compiler::{Engine, ShellEscape},
+///     Picture,
+/// };
+///
+/// let picture = Picture::new();
+/// let pdf_data = picture
+///     .to_pdf_bytes_with(&ShellEscape(Engine::PdfLatex))
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ShellEscape(pub Engine);
+
+impl ShellEscape {
+    fn args(&self) -> Vec<String> {
+        let mut args = self.0.args();
+        args.insert(0, String::from("-shell-escape"));
+        args
+    }
+}
+
+impl Compiler for ShellEscape {
+    fn compile(
+        &self,
+        write_source: &dyn Fn(&mut dyn io::Write) -> io::Result<()>,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        self.0.compile_with_args(&self.args(), write_source)
+    }
+}
+
+/// Run `program` inside `directory`, discarding all of its output.
+fn run(program: &str, args: &[String], directory: &Path) -> io::Result<ExitStatus> {
+    Command::new(program)
+        .args(args)
+        .current_dir(directory)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+}
+
+/// Run a program that converts the PDF document in `directory` into another
+/// format.
+fn convert(program: &str, args: &[String], directory: &Path) -> Result<(), ShowPdfError> {
+    let status = run(program, args, directory).map_err(ShowPdfError::Convert)?;
+    if !status.success() {
+        return Err(ShowPdfError::Convert(io::Error::other(format!(
+            "`{program}` exited with {status}"
+        ))));
+    }
+
+    Ok(())
+}
+
+fn svg_args() -> Vec<String> {
+    vec![
+        String::from("--pdf"),
+        // Glyphs are converted to paths. Otherwise the SVG image depends on
+        // the TeX fonts being available wherever it is displayed.
+        String::from("--no-fonts"),
+        format!("--output={JOB_NAME}.svg"),
+        format!("{JOB_NAME}.pdf"),
+    ]
+}
+
+/// Convert a PDF document into an SVG image with `dvisvgm`.
+pub(crate) fn pdf_to_svg(pdf_data: &[u8]) -> Result<String, ShowPdfError> {
+    let directory = tempfile::tempdir()?;
+    std::fs::write(directory.path().join(format!("{JOB_NAME}.pdf")), pdf_data)?;
+
+    convert("dvisvgm", &svg_args(), directory.path())?;
+
+    std::fs::read_to_string(directory.path().join(format!("{JOB_NAME}.svg")))
+        .map_err(ShowPdfError::Convert)
+}
+
+/// Background of a rasterized figure.
+#[cfg(feature = "png")]
+#[derive(Clone, Copy, Debug)]
+pub enum Background {
+    /// Areas not covered by the figure are transparent.
+    Transparent,
+    /// Areas not covered by the figure are white.
+    White,
+}
+
+#[cfg(feature = "png")]
+fn png_args(dpi: u32, background: Background) -> Vec<String> {
+    let mut args = vec![
+        String::from("-png"),
+        String::from("-singlefile"),
+        String::from("-r"),
+        dpi.to_string(),
+    ];
+    if let Background::Transparent = background {
+        args.push(String::from("-transparent"));
+    }
+    args.push(format!("{JOB_NAME}.pdf"));
+    // Output file name without extension; `.png` is appended automatically.
+    args.push(String::from(JOB_NAME));
+    args
+}
+
+/// Rasterize a PDF document into a PNG image with `pdftocairo`.
+#[cfg(feature = "png")]
+pub(crate) fn pdf_to_png(
+    pdf_data: &[u8],
+    dpi: u32,
+    background: Background,
+) -> Result<Vec<u8>, ShowPdfError> {
+    let directory = tempfile::tempdir()?;
+    std::fs::write(directory.path().join(format!("{JOB_NAME}.pdf")), pdf_data)?;
+
+    convert("pdftocairo", &png_args(dpi, background), directory.path())?;
+
+    std::fs::read(directory.path().join(format!("{JOB_NAME}.png"))).map_err(ShowPdfError::Convert)
+}
+
+/// Compile figures with the [tectonic](https://crates.io/crates/tectonic)
+/// crate, without relying on any externally installed software.
+#[cfg(feature = "inclusive")]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Tectonic;
+
+#[cfg(feature = "inclusive")]
+impl Compiler for Tectonic {
+    fn compile(
+        &self,
+        write_source: &dyn Fn(&mut dyn io::Write) -> io::Result<()>,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        use tectonic::{
+            config::PersistentConfig,
+            driver::{OutputFormat, ProcessingSessionBuilder},
+            status::NoopStatusBackend,
+        };
+
+        // Tectonic only takes the primary input from memory.
+        let mut source = Vec::new();
+        write_source(&mut source)?;
+
+        // Same as `tectonic::latex_to_pdf`, except that the log is kept in
+        // memory to build the error of a failed compilation.
+        let mut status = NoopStatusBackend::default();
+        let config = PersistentConfig::open(false)?;
+        let bundle = config.default_bundle(false, &mut status)?;
+        let format_cache_path = config.format_cache_path()?;
+
+        let mut builder = ProcessingSessionBuilder::default();
+        builder
+            .bundle(bundle)
+            .primary_input_buffer(&source)
+            .tex_input_name(&format!("{JOB_NAME}.tex"))
+            .format_name("latex")
+            .format_cache_path(format_cache_path)
+            .keep_logs(true)
+            .keep_intermediates(false)
+            .print_stdout(false)
+            .output_format(OutputFormat::Pdf)
+            .do_not_write_output_files();
+        let mut session = builder.create(&mut status)?;
+        let result = session.run(&mut status);
+
+        let mut files = session.into_file_data();
+        let log = files
+            .remove(&format!("{JOB_NAME}.log"))
+            .map(|file| String::from_utf8_lossy(&file.data).into_owned());
+        if let Err(error) = result {
+            let source = Box::new(TectonicError::new(&error));
+            return Err(CompileError::new(log, Some(source)).into());
+        }
+        match files.remove(&format!("{JOB_NAME}.pdf")) {
+            Some(file) => Ok(file.data),
+            None => Err(CompileError::new(log, Some("no PDF document was created".into())).into()),
+        }
+    }
+}
+
+/// Underlying error of a failed [`Tectonic`] compilation.
+///
+/// The errors returned by the `tectonic` crate are not [`Sync`], so only their
+/// messages are kept.
+#[cfg(feature = "inclusive")]
+#[derive(Debug)]
+pub(crate) struct TectonicError {
+    message: String,
+}
+
+#[cfg(feature = "inclusive")]
+impl fmt::Display for TectonicError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+#[cfg(feature = "inclusive")]
+impl Error for TectonicError {}
+
+#[cfg(feature = "inclusive")]
+impl TectonicError {
+    pub(crate) fn new(error: &tectonic::errors::Error) -> Self {
+        let mut message = error.to_string();
+        let mut source = error.source();
+        while let Some(error) = source {
+            message.push_str(&format!(": {error}"));
+            source = error.source();
+        }
+
+        TectonicError { message }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,228 @@
+use super::*;
+
+#[test]
+fn engine_program() {
+    assert_eq!(Engine::PdfLatex.program(), "pdflatex");
+    assert_eq!(Engine::LuaLatex.program(), "lualatex");
+    assert_eq!(Engine::XeLatex.program(), "xelatex");
+    assert_eq!(Engine::Latexmk.program(), "latexmk");
+}
+
+#[test]
+fn engine_args() {
+    assert_eq!(
+        Engine::PdfLatex.args(),
+        vec![
+            "-interaction=nonstopmode",
+            "-halt-on-error",
+            "-jobname=figure",
+            "figure.tex"
+        ]
+    );
+    assert_eq!(Engine::LuaLatex.args(), Engine::PdfLatex.args());
+    assert_eq!(Engine::XeLatex.args(), Engine::PdfLatex.args());
+    assert_eq!(
+        Engine::Latexmk.args(),
+        vec![
+            "-pdf",
+            "-interaction=nonstopmode",
+            "-halt-on-error",
+            "-jobname=figure",
+            "figure.tex"
+        ]
+    );
+}
+
+#[test]
+fn shell_escape_args() {
+    assert_eq!(
+        ShellEscape(Engine::PdfLatex).args(),
+        vec![
+            "-shell-escape",
+            "-interaction=nonstopmode",
+            "-halt-on-error",
+            "-jobname=figure",
+            "figure.tex"
+        ]
+    );
+    assert_eq!(
+        ShellEscape(Engine::Latexmk).args(),
+        vec![
+            "-shell-escape",
+            "-pdf",
+            "-interaction=nonstopmode",
+            "-halt-on-error",
+            "-jobname=figure",
+            "figure.tex"
+        ]
+    );
+}
+
+#[test]
+fn latex_error_parse() {
+    assert_eq!(LatexError::parse(""), None);
+    assert_eq!(LatexError::parse("This is pdfTeX\nOutput written"), None);
+
+    let log = r#"(./figure.tex
+! Undefined control sequence.
+l.3 \foo
+
+! Emergency stop.
+"#;
+    assert_eq!(
+        LatexError::parse(log),
+        Some(LatexError {
+            message: String::from("Undefined control sequence."),
+            line: Some(3),
+        })
+    );
+
+    let log = "! LaTeX Error: File `missing.sty' not found.\n\nType X to quit.\n";
+    assert_eq!(
+        LatexError::parse(log),
+        Some(LatexError {
+            message: String::from("LaTeX Error: File `missing.sty' not found."),
+            line: None,
+        })
+    );
+
+    let log = r#"! pdfTeX error: cannot open file.
+==> Fatal error occurred, no output PDF file produced!
+
+! Undefined control sequence.
+l.7 \foo
+"#;
+    assert_eq!(
+        LatexError::parse(log),
+        Some(LatexError {
+            message: String::from("pdfTeX error: cannot open file."),
+            line: None,
+        })
+    );
+}
+
+#[test]
+fn latex_error_parse_wrapped() {
+    let log = r#"! Package pgfkeys Error: I do not know the key '/pgfplots/colour', to which you
+ passed 'red', and I am going to ignore it. Perhaps you misspelled it.
+
+See the pgfkeys package documentation for explanation.
+Type  H <return>  for immediate help.
+ ...
+
+l.12 \end{axis}
+"#;
+    assert_eq!(
+        LatexError::parse(log),
+        Some(LatexError {
+            message: String::from("Package pgfkeys Error: I do not know the key '/pgfplots/colour', to which you passed 'red', and I am going to ignore it. Perhaps you misspelled it."),
+            line: Some(12),
+        })
+    );
+}
+
+#[test]
+fn latex_error_to_string() {
+    let error = LatexError {
+        message: String::from("Undefined control sequence."),
+        line: Some(3),
+    };
+    assert_eq!(error.to_string(), "Undefined control sequence. on line 3");
+
+    let error = LatexError {
+        message: String::from("Undefined control sequence."),
+        line: None,
+    };
+    assert_eq!(error.to_string(), "Undefined control sequence.");
+}
+
+#[test]
+fn compile_error_new() {
+    let error = CompileError::new(None, None);
+    assert!(error.log().is_none());
+    assert!(error.latex_error().is_none());
+    assert!(error.source().is_none());
+    assert_eq!(error.to_string(), "LaTeX compilation failed");
+
+    let error = CompileError::new(None, Some("engine exited".into()));
+    assert!(error.latex_error().is_none());
+    assert_eq!(error.source().unwrap().to_string(), "engine exited");
+    assert_eq!(error.to_string(), "engine exited");
+
+    let log = String::from("! Undefined control sequence.\nl.7 \\foo");
+    let error = CompileError::new(Some(log.clone()), Some("engine exited".into()));
+    assert_eq!(error.log(), Some(log.as_str()));
+    assert_eq!(error.latex_error().unwrap().line, Some(7));
+    assert_eq!(error.to_string(), "Undefined control sequence. on line 7");
+}
+
+// Returns the LaTeX code instead of compiling it.
+struct Echo;
+
+impl Compiler for Echo {
+    fn compile(
+        &self,
+        write_source: &dyn Fn(&mut dyn io::Write) -> io::Result<()>,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        let mut source = Vec::new();
+        write_source(&mut source)?;
+        Ok(source)
+    }
+}
+
+#[test]
+fn compiler_streams_standalone() {
+    let picture = Picture::new();
+    assert_eq!(
+        picture.to_pdf_bytes_with(&Echo).unwrap(),
+        picture.standalone_string().into_bytes()
+    );
+}
+
+#[test]
+fn svg_args_order() {
+    assert_eq!(
+        svg_args(),
+        vec!["--pdf", "--no-fonts", "--output=figure.svg", "figure.pdf"]
+    );
+}
+
+#[test]
+fn pdf_to_svg_invalid_pdf() {
+    // Fails regardless of whether `dvisvgm` is installed or not.
+    assert!(matches!(
+        pdf_to_svg(b"not a PDF document"),
+        Err(ShowPdfError::Convert(_))
+    ));
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn png_args_background() {
+    assert_eq!(
+        png_args(300, Background::White),
+        vec!["-png", "-singlefile", "-r", "300", "figure.pdf", "figure"]
+    );
+    assert_eq!(
+        png_args(72, Background::Transparent),
+        vec![
+            "-png",
+            "-singlefile",
+            "-r",
+            "72",
+            "-transparent",
+            "figure.pdf",
+            "figure"
+        ]
+    );
+}
+
+#[cfg(feature = "png")]
+#[test]
+fn pdf_to_png_invalid_pdf() {
+    // Fails regardless of whether `pdftocairo` is installed or not.
+    assert!(matches!(
+        pdf_to_png(b"not a PDF document", 300, Background::White),
+        Err(ShowPdfError::Convert(_))
+    ));
+}
@@ -0,0 +1,346 @@
+use crate::{
+    number::{Notation, NumberFormat},
+    preamble::Preamble,
+};
+use std::{fmt, ops};
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{
+    axis::{Axis, AxisKey},
+    Picture,
+};
+
+/// Length written into LaTeX code e.g. `5cm` or `0.5\linewidth`.
+///
+/// Lengths can be scaled by a number, and added to or subtracted from each
+/// other if they have the same unit or if both have an absolute unit (the
+/// result is then in [`Unit::Pt`]). The value is written like any other number
+/// (see [`NumberFormat`]), except that it never uses scientific notation.
+///
+/// # Panics
+///
+/// Adding or subtracting lengths in incompatible units e.g. centimeters and
+/// `\linewidth` with `+` or `-` panics. Use [`Length::checked_add`] and
+/// [`Length::checked_sub`] to handle this case.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::length::{Length, Unit};
+///
+/// assert_eq!(Length::cm(5.0).to_string(), "5cm");
+/// assert_eq!(Length::line_width(0.5).to_string(), "0.5\\linewidth");
+/// assert_eq!(Length::new(12.0, Unit::Pt), Length::pt(12.0));
+///
+/// assert_eq!(Length::cm(2.0) * 1.5 - Length::cm(1.0), Length::cm(2.0));
+/// assert_eq!(Length::inches(1.0) + Length::pt(0.5), Length::pt(72.77));
+/// assert_eq!(Length::cm(1.0).checked_add(Length::line_width(0.5)), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Length {
+    /// Number of units.
+    pub value: f64,
+    pub unit: Unit,
+}
+
+impl fmt::Display for Length {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // TeX does not read dimensions in scientific notation.
+        let format = NumberFormat {
+            significant_digits: None,
+            notation: Notation::Fixed,
+        };
+        write!(f, "{}{}", format.display(self.value), self.unit)
+    }
+}
+
+impl Length {
+    /// Creates a length of `value` units.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::length::{Length, Unit};
+    ///
+    /// let length = Length::new(2.5, Unit::Mm);
+    /// assert_eq!(length.to_string(), "2.5mm");
+    /// ```
+    pub fn new(value: f64, unit: Unit) -> Self {
+        Length { value, unit }
+    }
+    /// Creates a length in TeX points ([`Unit::Pt`]).
+    pub fn pt(value: f64) -> Self {
+        Length::new(value, Unit::Pt)
+    }
+    /// Creates a length in millimeters ([`Unit::Mm`]).
+    pub fn mm(value: f64) -> Self {
+        Length::new(value, Unit::Mm)
+    }
+    /// Creates a length in centimeters ([`Unit::Cm`]).
+    pub fn cm(value: f64) -> Self {
+        Length::new(value, Unit::Cm)
+    }
+    /// Creates a length in inches ([`Unit::In`]).
+    pub fn inches(value: f64) -> Self {
+        Length::new(value, Unit::In)
+    }
+    /// Creates a length that is a `fraction` of the width of the current line
+    /// ([`Unit::LineWidth`]).
+    pub fn line_width(fraction: f64) -> Self {
+        Length::new(fraction, Unit::LineWidth)
+    }
+    /// Creates a length that is a `fraction` of the width of the text block
+    /// ([`Unit::TextWidth`]).
+    pub fn text_width(fraction: f64) -> Self {
+        Length::new(fraction, Unit::TextWidth)
+    }
+    /// Creates a length that is a `fraction` of the width of a column
+    /// ([`Unit::ColumnWidth`]).
+    pub fn column_width(fraction: f64) -> Self {
+        Length::new(fraction, Unit::ColumnWidth)
+    }
+    /// Creates a length in the units of the coordinates of an [`Axis`]
+    /// ([`Unit::Axis`]).
+    pub fn axis(value: f64) -> Self {
+        Length::new(value, Unit::Axis)
+    }
+    /// Return the length in TeX points, or [`None`] if its unit is not
+    /// absolute e.g. [`Unit::Em`] or [`Unit::LineWidth`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::length::Length;
+    ///
+    /// assert_eq!(Length::inches(2.0).to_pt(), Some(144.54));
+    /// assert_eq!(Length::line_width(1.0).to_pt(), None);
+    /// ```
+    pub fn to_pt(&self) -> Option<f64> {
+        // There are 72.27pt in one inch.
+        let units_per_inch = match self.unit {
+            Unit::Pt => return Some(self.value),
+            Unit::Bp => 72.0,
+            Unit::Mm => 25.4,
+            Unit::Cm => 2.54,
+            Unit::In => 1.0,
+            Unit::Em
+            | Unit::Ex
+            | Unit::LineWidth
+            | Unit::TextWidth
+            | Unit::ColumnWidth
+            | Unit::Axis => return None,
+        };
+        Some(self.value * 72.27 / units_per_inch)
+    }
+    /// Add two lengths, or return [`None`] if their units are incompatible.
+    /// Lengths with the same unit keep their unit; lengths with different
+    /// absolute units are converted to [`Unit::Pt`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::length::Length;
+    ///
+    /// let margin = Length::line_width(0.1);
+    /// assert_eq!(
+    ///     Length::line_width(1.0).checked_add(-margin),
+    ///     Some(Length::line_width(0.9))
+    /// );
+    /// assert_eq!(Length::line_width(1.0).checked_add(Length::pt(-10.0)), None);
+    /// ```
+    pub fn checked_add(self, rhs: Length) -> Option<Length> {
+        if self.unit == rhs.unit {
+            return Some(Length::new(self.value + rhs.value, self.unit));
+        }
+        match (self.to_pt(), rhs.to_pt()) {
+            (Some(lhs), Some(rhs)) => Some(Length::pt(lhs + rhs)),
+            _ => None,
+        }
+    }
+    /// Subtract two lengths, or return [`None`] if their units are
+    /// incompatible. See [`Length::checked_add`].
+    pub fn checked_sub(self, rhs: Length) -> Option<Length> {
+        self.checked_add(-rhs)
+    }
+    /// Add the compatibility level needed by the length to the `preamble`.
+    pub(crate) fn add_requirements(&self, preamble: &mut Preamble) {
+        // Lengths without a unit are only read in axis units since 1.7.
+        if let Unit::Axis = self.unit {
+            preamble.require_compat("1.7");
+        }
+    }
+}
+
+impl ops::Add for Length {
+    type Output = Length;
+
+    /// Add two lengths. See [`Length::checked_add`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the units of the lengths are incompatible.
+    fn add(self, rhs: Length) -> Length {
+        self.checked_add(rhs)
+            .unwrap_or_else(|| panic!("cannot add lengths in `{}` and `{}`", self.unit, rhs.unit))
+    }
+}
+
+impl ops::Sub for Length {
+    type Output = Length;
+
+    /// Subtract two lengths. See [`Length::checked_sub`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the units of the lengths are incompatible.
+    fn sub(self, rhs: Length) -> Length {
+        self.checked_sub(rhs).unwrap_or_else(|| {
+            panic!(
+                "cannot subtract lengths in `{}` and `{}`",
+                self.unit, rhs.unit
+            )
+        })
+    }
+}
+
+impl ops::Neg for Length {
+    type Output = Length;
+
+    fn neg(self) -> Length {
+        Length::new(-self.value, self.unit)
+    }
+}
+
+impl ops::Mul<f64> for Length {
+    type Output = Length;
+
+    fn mul(self, rhs: f64) -> Length {
+        Length::new(self.value * rhs, self.unit)
+    }
+}
+
+impl ops::Mul<Length> for f64 {
+    type Output = Length;
+
+    fn mul(self, rhs: Length) -> Length {
+        rhs * self
+    }
+}
+
+impl ops::Div<f64> for Length {
+    type Output = Length;
+
+    fn div(self, rhs: f64) -> Length {
+        Length::new(self.value / rhs, self.unit)
+    }
+}
+
+/// Units of a [`Length`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Unit {
+    /// TeX point, `1/72.27` inches.
+    Pt,
+    /// Big (PostScript) point, `1/72` inches.
+    Bp,
+    /// Millimeter.
+    Mm,
+    /// Centimeter.
+    Cm,
+    /// Inch.
+    In,
+    /// Width of the letter `M` in the current font.
+    Em,
+    /// Height of the letter `x` in the current font.
+    Ex,
+    /// Width of the current line, `\linewidth`. Inside a figure this is
+    /// usually the width of the column.
+    LineWidth,
+    /// Width of the text block, `\textwidth`. In a two column document this
+    /// spans both columns.
+    TextWidth,
+    /// Width of a column, `\columnwidth`.
+    ColumnWidth,
+    /// Units of the coordinates of an [`Axis`]. The number is written without
+    /// a unit, which PGFPlots interprets in axis units for keys like the bar
+    /// width, provided that `compat=1.7` or higher is set in the
+    /// [`Picture::preamble`]. Otherwise, or for other keys, it is interpreted
+    /// in [`Unit::Pt`].
+    Axis,
+}
+impl fmt::Display for Unit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Unit::Pt => write!(f, "pt"),
+            Unit::Bp => write!(f, "bp"),
+            Unit::Mm => write!(f, "mm"),
+            Unit::Cm => write!(f, "cm"),
+            Unit::In => write!(f, "in"),
+            Unit::Em => write!(f, "em"),
+            Unit::Ex => write!(f, "ex"),
+            Unit::LineWidth => write!(f, "\\linewidth"),
+            Unit::TextWidth => write!(f, "\\textwidth"),
+            Unit::ColumnWidth => write!(f, "\\columnwidth"),
+            Unit::Axis => Ok(()),
+        }
+    }
+}
+
+/// Figure widths required by common journal templates.
+///
+/// The widths are exact, so they can be used for the [`AxisKey::Width`] of an
+/// [`Axis`] together with [`AxisKey::ScaleOnlyAxis`] (or with the labels
+/// accounted for) to match the dimensions required by a publisher.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::length::{ColumnPreset, Length};
+///
+/// assert_eq!(Length::from(ColumnPreset::IeeeSingle).to_string(), "3.5in");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ColumnPreset {
+    /// IEEE single column, `3.5in`.
+    IeeeSingle,
+    /// IEEE double column, `7.16in`.
+    IeeeDouble,
+    /// Elsevier single column, `90mm`.
+    ElsevierSingle,
+    /// Elsevier double column, `190mm`.
+    ElsevierDouble,
+    /// Springer single column, `84mm`.
+    SpringerSingle,
+    /// Springer double column, `174mm`.
+    SpringerDouble,
+    /// Nature single column, `89mm`.
+    NatureSingle,
+    /// Nature double column, `183mm`.
+    NatureDouble,
+    /// American Physical Society single column, `8.6cm`.
+    ApsSingle,
+    /// American Physical Society double column, `17.8cm`.
+    ApsDouble,
+}
+impl From<ColumnPreset> for Length {
+    fn from(preset: ColumnPreset) -> Self {
+        match preset {
+            ColumnPreset::IeeeSingle => Length::inches(3.5),
+            ColumnPreset::IeeeDouble => Length::inches(7.16),
+            ColumnPreset::ElsevierSingle => Length::mm(90.0),
+            ColumnPreset::ElsevierDouble => Length::mm(190.0),
+            ColumnPreset::SpringerSingle => Length::mm(84.0),
+            ColumnPreset::SpringerDouble => Length::mm(174.0),
+            ColumnPreset::NatureSingle => Length::mm(89.0),
+            ColumnPreset::NatureDouble => Length::mm(183.0),
+            ColumnPreset::ApsSingle => Length::cm(8.6),
+            ColumnPreset::ApsDouble => Length::cm(17.8),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
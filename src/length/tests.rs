@@ -0,0 +1,171 @@
+use super::*;
+
+// This test is here only to let us know if we added an enum variant
+// but we forgot to add unit tests for it
+//
+// If this fails, it is because you added a new variant.
+// Please do the following:
+// 1) Add a unit test for the new variant you added (see examples below).
+// 2) AFTER doing (1), add the new variant to the match.
+#[test]
+fn units_tested() {
+    let unit = Unit::Pt;
+    match unit {
+        Unit::Pt => (),
+        Unit::Bp => (),
+        Unit::Mm => (),
+        Unit::Cm => (),
+        Unit::In => (),
+        Unit::Em => (),
+        Unit::Ex => (),
+        Unit::LineWidth => (),
+        Unit::TextWidth => (),
+        Unit::ColumnWidth => (),
+        Unit::Axis => (),
+    }
+}
+
+#[test]
+fn column_presets_tested() {
+    let preset = ColumnPreset::IeeeSingle;
+    match preset {
+        ColumnPreset::IeeeSingle => (),
+        ColumnPreset::IeeeDouble => (),
+        ColumnPreset::ElsevierSingle => (),
+        ColumnPreset::ElsevierDouble => (),
+        ColumnPreset::SpringerSingle => (),
+        ColumnPreset::SpringerDouble => (),
+        ColumnPreset::NatureSingle => (),
+        ColumnPreset::NatureDouble => (),
+        ColumnPreset::ApsSingle => (),
+        ColumnPreset::ApsDouble => (),
+    }
+}
+
+#[test]
+fn unit_to_string() {
+    assert_eq!(Unit::Pt.to_string(), "pt");
+    assert_eq!(Unit::Bp.to_string(), "bp");
+    assert_eq!(Unit::Mm.to_string(), "mm");
+    assert_eq!(Unit::Cm.to_string(), "cm");
+    assert_eq!(Unit::In.to_string(), "in");
+    assert_eq!(Unit::Em.to_string(), "em");
+    assert_eq!(Unit::Ex.to_string(), "ex");
+    assert_eq!(Unit::LineWidth.to_string(), "\\linewidth");
+    assert_eq!(Unit::TextWidth.to_string(), "\\textwidth");
+    assert_eq!(Unit::ColumnWidth.to_string(), "\\columnwidth");
+    assert_eq!(Unit::Axis.to_string(), "");
+}
+
+#[test]
+fn length_constructors() {
+    assert_eq!(Length::pt(1.0), Length::new(1.0, Unit::Pt));
+    assert_eq!(Length::mm(1.0), Length::new(1.0, Unit::Mm));
+    assert_eq!(Length::cm(1.0), Length::new(1.0, Unit::Cm));
+    assert_eq!(Length::inches(1.0), Length::new(1.0, Unit::In));
+    assert_eq!(Length::line_width(1.0), Length::new(1.0, Unit::LineWidth));
+    assert_eq!(Length::text_width(1.0), Length::new(1.0, Unit::TextWidth));
+    assert_eq!(
+        Length::column_width(1.0),
+        Length::new(1.0, Unit::ColumnWidth)
+    );
+    assert_eq!(Length::axis(1.0), Length::new(1.0, Unit::Axis));
+}
+
+#[test]
+fn length_to_string() {
+    assert_eq!(Length::cm(5.0).to_string(), "5cm");
+    assert_eq!(Length::pt(-0.25).to_string(), "-0.25pt");
+    assert_eq!(Length::text_width(0.45).to_string(), "0.45\\textwidth");
+    assert_eq!(Length::axis(0.8).to_string(), "0.8");
+    assert_eq!(Length::pt(1e-7).to_string(), "0.0000001pt");
+    assert_eq!(Length::pt(f64::NAN).to_string(), "nanpt");
+    assert_eq!(Length::cm(f64::NEG_INFINITY).to_string(), "-infcm");
+}
+
+#[test]
+fn length_to_pt() {
+    assert_eq!(Length::pt(3.0).to_pt(), Some(3.0));
+    assert_eq!(Length::new(72.0, Unit::Bp).to_pt(), Some(72.27));
+    assert_eq!(Length::mm(25.4).to_pt(), Some(72.27));
+    assert_eq!(Length::cm(2.54).to_pt(), Some(72.27));
+    assert_eq!(Length::inches(1.0).to_pt(), Some(72.27));
+    assert_eq!(Length::new(1.0, Unit::Em).to_pt(), None);
+    assert_eq!(Length::new(1.0, Unit::Ex).to_pt(), None);
+    assert_eq!(Length::line_width(1.0).to_pt(), None);
+    assert_eq!(Length::text_width(1.0).to_pt(), None);
+    assert_eq!(Length::column_width(1.0).to_pt(), None);
+    assert_eq!(Length::axis(1.0).to_pt(), None);
+}
+
+#[test]
+fn length_checked_add() {
+    assert_eq!(
+        Length::cm(1.0).checked_add(Length::cm(2.0)),
+        Some(Length::cm(3.0))
+    );
+    assert_eq!(
+        Length::axis(1.0).checked_add(Length::axis(0.5)),
+        Some(Length::axis(1.5))
+    );
+    assert_eq!(
+        Length::inches(1.0).checked_add(Length::pt(1.0)),
+        Some(Length::pt(73.27))
+    );
+    assert_eq!(Length::axis(1.0).checked_add(Length::pt(1.0)), None);
+    assert_eq!(
+        Length::line_width(1.0).checked_add(Length::text_width(1.0)),
+        None
+    );
+}
+
+#[test]
+fn length_checked_sub() {
+    assert_eq!(
+        Length::text_width(1.0).checked_sub(Length::text_width(0.25)),
+        Some(Length::text_width(0.75))
+    );
+    assert_eq!(Length::text_width(1.0).checked_sub(Length::cm(1.0)), None);
+}
+
+#[test]
+fn length_arithmetic() {
+    assert_eq!(Length::pt(1.0) + Length::pt(2.0), Length::pt(3.0));
+    assert_eq!(Length::pt(1.0) - Length::pt(2.0), Length::pt(-1.0));
+    assert_eq!(-Length::mm(1.0), Length::mm(-1.0));
+    assert_eq!(Length::mm(1.5) * 2.0, Length::mm(3.0));
+    assert_eq!(2.0 * Length::mm(1.5), Length::mm(3.0));
+    assert_eq!(Length::mm(3.0) / 2.0, Length::mm(1.5));
+}
+
+#[test]
+#[should_panic(expected = "cannot add lengths in `cm` and `\\linewidth`")]
+fn length_add_incompatible() {
+    let _ = Length::cm(1.0) + Length::line_width(1.0);
+}
+
+#[test]
+#[should_panic(expected = "cannot subtract lengths in `cm` and ``")]
+fn length_sub_incompatible() {
+    let _ = Length::cm(1.0) - Length::axis(1.0);
+}
+
+#[test]
+fn length_from_column_preset() {
+    assert_eq!(Length::from(ColumnPreset::IeeeSingle), Length::inches(3.5));
+    assert_eq!(Length::from(ColumnPreset::IeeeDouble), Length::inches(7.16));
+    assert_eq!(Length::from(ColumnPreset::ElsevierSingle), Length::mm(90.0));
+    assert_eq!(
+        Length::from(ColumnPreset::ElsevierDouble),
+        Length::mm(190.0)
+    );
+    assert_eq!(Length::from(ColumnPreset::SpringerSingle), Length::mm(84.0));
+    assert_eq!(
+        Length::from(ColumnPreset::SpringerDouble),
+        Length::mm(174.0)
+    );
+    assert_eq!(Length::from(ColumnPreset::NatureSingle), Length::mm(89.0));
+    assert_eq!(Length::from(ColumnPreset::NatureDouble), Length::mm(183.0));
+    assert_eq!(Length::from(ColumnPreset::ApsSingle), Length::cm(8.6));
+    assert_eq!(Length::from(ColumnPreset::ApsDouble), Length::cm(17.8));
+}
@@ -28,6 +28,17 @@
 //! plot.show();
 //! ```
 //!
+//! Without the `inclusive` feature, figures are compiled with any of the
+//! LaTeX engines installed in your system (see [`compiler::Engine`]):
+//!
+//! ```no_run
+//! # use pgfplots::axis::plot::Plot2D;
+//! use pgfplots::compiler::Engine;
+//!
+//! # let plot = Plot2D::new();
+//! plot.show_with(&Engine::PdfLatex);
+//! ```
+//!
 //! It is possible to show multiple plots in the same axis environment by
 //! creating an [`Axis`] and adding plots to it. An [`Axis`] and its individual
 //! [`Plot2D`]s are customized by [`AxisKey`]s and [`PlotKey`]s respectively.
@@ -36,65 +47,104 @@
 // case, please change it.
 #[allow(unused_imports)]
 use crate::axis::{
-    plot::{Plot2D, PlotKey},
+    plot::{fill_between::FillBetween, PlotKey},
     AxisKey,
 };
 
-use crate::axis::Axis;
-use std::fmt;
+use crate::axis::{
+    plot::{Plot2D, Plot3D},
+    Axis,
+};
+use crate::compiler::{CompileError, Compiler};
+use crate::number::NumberFormat;
+use crate::preamble::Preamble;
+use std::{
+    fmt,
+    io::{self, Write},
+    path::Path,
+};
 
+#[cfg(feature = "png")]
+use crate::compiler::Background;
 #[cfg(feature = "inclusive")]
-use std::io::Write;
+use crate::compiler::{Tectonic, TectonicError};
 
 /// Axis environment inside a [`Picture`].
 pub mod axis;
+/// Colors written into LaTeX code e.g. the color of a plot.
+pub mod color;
+/// Backends used to compile figures into PDF documents.
+pub mod compiler;
+/// Lengths written into LaTeX code e.g. the size of an axis.
+pub mod length;
+/// Formatting of the numbers written into LaTeX code.
+pub mod number;
+/// Preamble of the standalone document of a [`Picture`].
+pub mod preamble;
 
 /// The error type returned when showing a figure fails.
-#[cfg(feature = "inclusive")]
-#[derive(Clone, Copy, Debug)]
+#[derive(Debug)]
 pub enum ShowPdfError {
-    /// Compilation of LaTeX source failed internally
-    Compile,
-    /// Creating or writing to temporary file failed
-    Write,
+    /// Compilation of LaTeX source failed
+    Compile(CompileError),
+    /// Creating or writing to a file failed
+    Write(std::io::Error),
     /// Persisting the temporary file failed
-    Persist,
+    Persist(tempfile::PersistError),
     /// Opening the file failed
-    Open,
+    Open(opener::OpenError),
+    /// Converting the PDF into another format failed
+    Convert(std::io::Error),
 }
-#[cfg(feature = "inclusive")]
 impl fmt::Display for ShowPdfError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match *self {
-            ShowPdfError::Compile => write!(f, "tectonic compilation error"),
-            ShowPdfError::Write => write!(f, "creating or writing to temporary file failed"),
-            ShowPdfError::Persist => write!(f, "persisting temporary file failed"),
-            ShowPdfError::Open => write!(f, "opening file error"),
+        match self {
+            ShowPdfError::Compile(_) => write!(f, "LaTeX compilation error"),
+            ShowPdfError::Write(_) => write!(f, "creating or writing to file failed"),
+            ShowPdfError::Persist(_) => write!(f, "persisting temporary file failed"),
+            ShowPdfError::Open(_) => write!(f, "opening file error"),
+            ShowPdfError::Convert(_) => write!(f, "converting PDF to another format failed"),
         }
     }
 }
+impl std::error::Error for ShowPdfError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ShowPdfError::Compile(error) => Some(error),
+            ShowPdfError::Write(error) => Some(error),
+            ShowPdfError::Persist(error) => Some(error),
+            ShowPdfError::Open(error) => Some(error),
+            ShowPdfError::Convert(error) => Some(error),
+        }
+    }
+}
+impl From<CompileError> for ShowPdfError {
+    fn from(error: CompileError) -> Self {
+        Self::Compile(error)
+    }
+}
 #[cfg(feature = "inclusive")]
 impl From<tectonic::errors::Error> for ShowPdfError {
-    fn from(_: tectonic::errors::Error) -> Self {
-        Self::Compile
+    fn from(error: tectonic::errors::Error) -> Self {
+        Self::Compile(CompileError::new(
+            None,
+            Some(Box::new(TectonicError::new(&error))),
+        ))
     }
 }
-#[cfg(feature = "inclusive")]
 impl From<std::io::Error> for ShowPdfError {
-    fn from(_: std::io::Error) -> Self {
-        Self::Write
+    fn from(error: std::io::Error) -> Self {
+        Self::Write(error)
     }
 }
-#[cfg(feature = "inclusive")]
 impl From<tempfile::PersistError> for ShowPdfError {
-    fn from(_: tempfile::PersistError) -> Self {
-        Self::Persist
+    fn from(error: tempfile::PersistError) -> Self {
+        Self::Persist(error)
     }
 }
-#[cfg(feature = "inclusive")]
 impl From<opener::OpenError> for ShowPdfError {
-    fn from(_: opener::OpenError) -> Self {
-        Self::Open
+    fn from(error: opener::OpenError) -> Self {
+        Self::Open(error)
     }
 }
 
@@ -131,29 +181,28 @@ impl fmt::Display for PictureKey {
 /// ```
 ///
 /// You will rarely interact with a [`Picture`]. It is only useful to generate
-/// complex layouts with multiple axis environments.
+/// complex layouts with multiple axis environments, or to customize the
+/// [`Preamble`] of the standalone document.
 #[derive(Clone, Debug, Default)]
 pub struct Picture {
     keys: Vec<PictureKey>,
     pub axes: Vec<Axis>,
+    /// Preamble used by [`Picture::standalone_string`] and all the methods
+    /// that compile the picture.
+    pub preamble: Preamble,
+    /// Format of the numbers in the keys (e.g. limits and ticks) of all the
+    /// axes, and in the coordinates of all the plots that do not have their
+    /// own [`Plot2D::number_format`](axis::plot::Plot2D::number_format).
+    pub number_format: NumberFormat,
 }
 
 impl fmt::Display for Picture {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "\\begin{{tikzpicture}}")?;
-        // If there are keys, print one per line. It makes it easier for a
-        // human later to find keys if they are divided by lines.
-        if !self.keys.is_empty() {
-            writeln!(f, "[")?;
-            for key in self.keys.iter() {
-                writeln!(f, "\t{key},")?;
-            }
-            write!(f, "]")?;
-        }
-        writeln!(f)?;
+        self.fmt_begin(f)?;
 
         for axis in self.axes.iter() {
-            writeln!(f, "{axis}")?;
+            axis.fmt_with(f, self.number_format)?;
+            writeln!(f)?;
         }
 
         write!(f, "\\end{{tikzpicture}}")?;
@@ -194,8 +243,34 @@ impl Picture {
         }
         self.keys.push(key);
     }
+    /// Write the `\begin{tikzpicture}[PictureKeys]` line.
+    fn fmt_begin(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\\begin{{tikzpicture}}")?;
+        // If there are keys, print one per line. It makes it easier for a
+        // human later to find keys if they are divided by lines.
+        if !self.keys.is_empty() {
+            writeln!(f, "[")?;
+            for key in self.keys.iter() {
+                writeln!(f, "\t{key},")?;
+            }
+            write!(f, "]")?;
+        }
+        writeln!(f)
+    }
+    /// Add the packages and libraries needed by the picture, its axes and
+    /// their plots to the `preamble`.
+    fn add_requirements(&self, preamble: &mut Preamble) {
+        // Custom keys are opaque. Users have to add whatever they need to the
+        // preamble themselves.
+        for axis in self.axes.iter() {
+            axis.add_requirements(preamble);
+        }
+    }
     /// Return a [`String`] with valid LaTeX code that generates a standalone
-    /// PDF with the picture environment.
+    /// PDF with the picture environment. Everything before `\begin{document}`
+    /// is controlled by the [`Picture::preamble`], to which any package or
+    /// library needed by the keys and plots in the picture is added
+    /// automatically.
     ///
     /// # Note
     ///
@@ -213,6 +288,7 @@ impl Picture {
     /// assert_eq!(
     /// r#"\documentclass{standalone}
     /// \usepackage{pgfplots}
+    /// \pgfplotsset{compat=1.16}
     /// \begin{document}
     /// \begin{tikzpicture}
     /// \end{tikzpicture}
@@ -220,11 +296,72 @@ impl Picture {
     /// picture.standalone_string());
     /// ```
     pub fn standalone_string(&self) -> String {
-        String::from("\\documentclass{standalone}\n")
-            + "\\usepackage{pgfplots}\n"
-            + "\\begin{document}\n"
-            + &self.to_string()
-            + "\n\\end{document}"
+        Standalone::Picture(self).to_string()
+    }
+    /// Write the same LaTeX code returned by [`Picture::standalone_string`]
+    /// into `writer`. The code is streamed as it is generated, so no
+    /// intermediate [`String`] is built. You probably want to wrap unbuffered
+    /// writers (e.g. a [`std::fs::File`]) in a [`std::io::BufWriter`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::Picture;
+    /// use std::{fs::File, io::BufWriter};
+    ///
+    /// let mut picture = Picture::new();
+    /// let file = File::create("figure.tex").unwrap();
+    /// picture.write_standalone(BufWriter::new(file)).unwrap();
+    /// ```
+    pub fn write_standalone<W: io::Write>(&self, writer: W) -> io::Result<()> {
+        Standalone::Picture(self).write(writer)
+    }
+    /// Export the picture as a LaTeX snippet to be included in a larger
+    /// document, with the coordinates of each plot written to a separate data
+    /// file. All files are written in `directory` (which is created if it
+    /// doesn't exist):
+    ///
+    /// - `<name>.tex`: The picture environment, ready to be included with
+    ///   `\input{<directory>/<name>.tex}`. The required packages and libraries
+    ///   have to be loaded in the preamble of your document.
+    /// - `<name>-<i>-<j>.dat`: The coordinates of the `j`-th plot in the `i`-th
+    ///   axis, which are read with `\addplot table`. Plots without coordinates
+    ///   (e.g. a [`FillBetween`]) have no data file.
+    ///
+    /// The data files are referred to with `directory` as given, so a relative
+    /// `directory` should be relative to where your main document is compiled.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{axis::Axis, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.axes.push(Axis::new());
+    /// // Writes `figures/quadratic.tex` and `figures/quadratic-0-*.dat`
+    /// picture.export("figures", "quadratic").unwrap();
+    /// ```
+    pub fn export<P: AsRef<Path>>(&self, directory: P, name: &str) -> std::io::Result<()> {
+        let directory = directory.as_ref();
+        std::fs::create_dir_all(directory)?;
+
+        for (i, axis) in self.axes.iter().enumerate() {
+            for (j, plot) in axis.plots.iter().enumerate() {
+                if !plot.has_table() {
+                    continue;
+                }
+                let file = std::fs::File::create(directory.join(data_file_name(name, i, j)))?;
+                plot.write_table(std::io::BufWriter::new(file), self.number_format)?;
+            }
+        }
+
+        let snippet = ExportedPicture {
+            picture: self,
+            // LaTeX expects forward slashes on every platform.
+            directory: directory.to_string_lossy().replace('\\', "/"),
+            name,
+        };
+        std::fs::write(directory.join(format!("{name}.tex")), snippet.to_string())
     }
     /// Show the picture as a standalone PDF. This will create a file in the
     /// location returned by [`std::env::temp_dir()`] and open it with the
@@ -240,7 +377,264 @@ impl Picture {
     /// ```
     #[cfg(feature = "inclusive")]
     pub fn show(&self) -> Result<(), ShowPdfError> {
-        let pdf_data = tectonic::latex_to_pdf(self.standalone_string())?;
+        self.show_with(&Tectonic)
+    }
+    /// Same as [`Picture::show`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{compiler::Engine, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.show_with(&Engine::LuaLatex);
+    /// ```
+    pub fn show_with<C: Compiler + ?Sized>(&self, compiler: &C) -> Result<(), ShowPdfError> {
+        Standalone::Picture(self).show_with(compiler)
+    }
+    /// Compile the picture as a standalone PDF and return its raw bytes. This
+    /// does not create any file nor open a PDF viewer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// let pdf_data = picture.to_pdf_bytes().unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn to_pdf_bytes(&self) -> Result<Vec<u8>, ShowPdfError> {
+        self.to_pdf_bytes_with(&Tectonic)
+    }
+    /// Same as [`Picture::to_pdf_bytes`], but the PDF is compiled with the
+    /// given [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{compiler::Engine, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// let pdf_data = picture.to_pdf_bytes_with(&Engine::PdfLatex).unwrap();
+    /// ```
+    pub fn to_pdf_bytes_with<C: Compiler + ?Sized>(
+        &self,
+        compiler: &C,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        Standalone::Picture(self).to_pdf_bytes_with(compiler)
+    }
+    /// Compile the picture as a standalone PDF and write it to `path`. If the
+    /// file already exists, it will be overwritten. Unlike [`Picture::show`],
+    /// this will not open a PDF viewer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.save_pdf("figure.pdf").unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn save_pdf<P: AsRef<Path>>(&self, path: P) -> Result<(), ShowPdfError> {
+        self.save_pdf_with(&Tectonic, path)
+    }
+    /// Same as [`Picture::save_pdf`], but the PDF is compiled with the given
+    /// [`Compiler`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{compiler::Engine, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.save_pdf_with(&Engine::XeLatex, "figure.pdf").unwrap();
+    /// ```
+    pub fn save_pdf_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        &self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        Standalone::Picture(self).save_pdf_with(compiler, path)
+    }
+    /// Compile the picture as a standalone SVG image and return its contents.
+    /// The image is converted from the same PDF document generated by
+    /// [`Picture::to_pdf_bytes`], so both look exactly the same.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `dvisvgm` (included in most LaTeX
+    /// distributions) to be available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// let svg_data = picture.to_svg().unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn to_svg(&self) -> Result<String, ShowPdfError> {
+        self.to_svg_with(&Tectonic)
+    }
+    /// Same as [`Picture::to_svg`], but the PDF is compiled with the given
+    /// [`Compiler`] before converting it into an SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{compiler::Engine, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// let svg_data = picture.to_svg_with(&Engine::PdfLatex).unwrap();
+    /// ```
+    pub fn to_svg_with<C: Compiler + ?Sized>(&self, compiler: &C) -> Result<String, ShowPdfError> {
+        Standalone::Picture(self).to_svg_with(compiler)
+    }
+    /// Compile the picture as a standalone SVG image and write it to `path`. If
+    /// the file already exists, it will be overwritten.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `dvisvgm` (included in most LaTeX
+    /// distributions) to be available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::Picture;
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.save_svg("figure.svg").unwrap();
+    /// ```
+    #[cfg(feature = "inclusive")]
+    pub fn save_svg<P: AsRef<Path>>(&self, path: P) -> Result<(), ShowPdfError> {
+        self.save_svg_with(&Tectonic, path)
+    }
+    /// Same as [`Picture::save_svg`], but the PDF is compiled with the given
+    /// [`Compiler`] before converting it into an SVG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{compiler::Engine, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// picture.save_svg_with(&Engine::XeLatex, "figure.svg").unwrap();
+    /// ```
+    pub fn save_svg_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        &self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        Standalone::Picture(self).save_svg_with(compiler, path)
+    }
+    /// Compile the picture as a standalone PNG image and return its raw bytes.
+    /// The image is rasterized from the same PDF document generated by
+    /// [`Picture::to_pdf_bytes`] with a resolution of `dpi` dots per inch.
+    ///
+    /// # Note
+    ///
+    /// The conversion requires `pdftocairo` (included in Poppler) to be
+    /// available in your `PATH`.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{compiler::Background, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// let png_data = picture.to_png(300, Background::White).unwrap();
+    /// ```
+    #[cfg(all(feature = "inclusive", feature = "png"))]
+    pub fn to_png(&self, dpi: u32, background: Background) -> Result<Vec<u8>, ShowPdfError> {
+        self.to_png_with(&Tectonic, dpi, background)
+    }
+    /// Same as [`Picture::to_png`], but the PDF is compiled with the given
+    /// [`Compiler`] before rasterizing it into a PNG image.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use pgfplots::{compiler::{Background, Engine}, Picture};
+    ///
+    /// let mut picture = Picture::new();
+    /// let png_data = picture
+    ///     .to_png_with(&Engine::PdfLatex, 150, Background::Transparent)
+    ///     .unwrap();
+    /// ```
+    #[cfg(feature = "png")]
+    pub fn to_png_with<C: Compiler + ?Sized>(
+        &self,
+        compiler: &C,
+        dpi: u32,
+        background: Background,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        Standalone::Picture(self).to_png_with(compiler, dpi, background)
+    }
+}
+
+/// Standalone LaTeX document with a figure.
+///
+/// The figure is borrowed and, if needed, wrapped in default [`Axis`] and
+/// [`Picture`] environments while it is written. This avoids cloning large
+/// figures just to render or compile them.
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum Standalone<'a> {
+    Picture(&'a Picture),
+    Axis(&'a Axis),
+    Plot2D(&'a Plot2D),
+    Plot3D(&'a Plot3D),
+}
+
+impl fmt::Display for Standalone<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut preamble = match self {
+            Standalone::Picture(picture) => picture.preamble.clone(),
+            Standalone::Axis(_) | Standalone::Plot2D(_) | Standalone::Plot3D(_) => Preamble::new(),
+        };
+        match self {
+            Standalone::Picture(picture) => picture.add_requirements(&mut preamble),
+            Standalone::Axis(axis) => axis.add_requirements(&mut preamble),
+            Standalone::Plot2D(plot) => plot.add_requirements(&mut preamble),
+            Standalone::Plot3D(plot) => plot.add_requirements(&mut preamble),
+        }
+        writeln!(f, "{preamble}\\begin{{document}}")?;
+
+        // The default environments are the same as those of a new Picture and
+        // a new Axis.
+        match self {
+            Standalone::Picture(picture) => writeln!(f, "{picture}")?,
+            Standalone::Axis(axis) => {
+                writeln!(f, "\\begin{{tikzpicture}}\n{axis}\n\\end{{tikzpicture}}")?
+            }
+            Standalone::Plot2D(plot) => {
+                writeln!(f, "\\begin{{tikzpicture}}\n\\begin{{axis}}\n{plot}")?;
+                if let Some(legend) = plot.legend_entry() {
+                    writeln!(f, "\t\\addlegendentry{{{legend}}}")?;
+                }
+                writeln!(f, "\\end{{axis}}\n\\end{{tikzpicture}}")?
+            }
+            Standalone::Plot3D(plot) => writeln!(
+                f,
+                "\\begin{{tikzpicture}}\n\\begin{{axis}}\n{plot}\n\\end{{axis}}\n\\end{{tikzpicture}}"
+            )?,
+        }
+
+        write!(f, "\\end{{document}}")
+    }
+}
+
+impl Standalone<'_> {
+    pub(crate) fn write<W: io::Write>(self, mut writer: W) -> io::Result<()> {
+        write!(writer, "{self}")?;
+        writer.flush()
+    }
+    pub(crate) fn show_with<C: Compiler + ?Sized>(self, compiler: &C) -> Result<(), ShowPdfError> {
+        let pdf_data = self.to_pdf_bytes_with(compiler)?;
 
         let mut file = tempfile::NamedTempFile::new()?;
         file.write_all(&pdf_data)?;
@@ -250,6 +644,78 @@ impl Picture {
 
         Ok(())
     }
+    pub(crate) fn to_pdf_bytes_with<C: Compiler + ?Sized>(
+        self,
+        compiler: &C,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        compiler.compile(&|writer| self.write(writer))
+    }
+    pub(crate) fn save_pdf_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        let pdf_data = self.to_pdf_bytes_with(compiler)?;
+        std::fs::write(path, pdf_data)?;
+
+        Ok(())
+    }
+    pub(crate) fn to_svg_with<C: Compiler + ?Sized>(
+        self,
+        compiler: &C,
+    ) -> Result<String, ShowPdfError> {
+        compiler::pdf_to_svg(&self.to_pdf_bytes_with(compiler)?)
+    }
+    pub(crate) fn save_svg_with<C: Compiler + ?Sized, P: AsRef<Path>>(
+        self,
+        compiler: &C,
+        path: P,
+    ) -> Result<(), ShowPdfError> {
+        let svg_data = self.to_svg_with(compiler)?;
+        std::fs::write(path, svg_data)?;
+
+        Ok(())
+    }
+    #[cfg(feature = "png")]
+    pub(crate) fn to_png_with<C: Compiler + ?Sized>(
+        self,
+        compiler: &C,
+        dpi: u32,
+        background: Background,
+    ) -> Result<Vec<u8>, ShowPdfError> {
+        compiler::pdf_to_png(&self.to_pdf_bytes_with(compiler)?, dpi, background)
+    }
+}
+
+/// File name of the data file of the `j`-th plot in the `i`-th axis of an
+/// exported picture.
+fn data_file_name(name: &str, i: usize, j: usize) -> String {
+    format!("{name}-{i}-{j}.dat")
+}
+
+/// Picture environment in which the plots read their coordinates from the data
+/// files written by [`Picture::export`].
+struct ExportedPicture<'a> {
+    picture: &'a Picture,
+    directory: String,
+    name: &'a str,
+}
+
+impl fmt::Display for ExportedPicture<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.picture.fmt_begin(f)?;
+
+        for (i, axis) in self.picture.axes.iter().enumerate() {
+            axis.fmt_begin(f, self.picture.number_format)?;
+            axis.fmt_plots(f, |f, j, plot| {
+                let path = format!("{}/{}", self.directory, data_file_name(self.name, i, j));
+                plot.fmt_table(f, &path, self.picture.number_format)
+            })?;
+            writeln!(f, "\\end{{axis}}")?;
+        }
+
+        write!(f, "\\end{{tikzpicture}}")
+    }
 }
 
 #[cfg(test)]
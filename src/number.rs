@@ -0,0 +1,134 @@
+use std::fmt;
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::{axis::plot::Plot2D, Picture};
+
+/// Format used to write numbers (e.g. coordinates) into LaTeX code.
+///
+/// The default format writes the shortest representation that round-trips to
+/// the same [`f64`]. Regardless of the format, NaN and infinite values are
+/// written as `nan`, `inf`, and `-inf`, which are understood by PGFPlots.
+///
+/// A format can be set for a whole [`Picture`] and overridden by any
+/// [`Plot2D`].
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::number::{NumberFormat, Notation};
+///
+/// let format = NumberFormat {
+///     significant_digits: Some(3),
+///     notation: Notation::Auto,
+/// };
+/// assert_eq!(format.display(0.1 + 0.2).to_string(), "0.3");
+/// assert_eq!(format.display(123456.0).to_string(), "123000");
+/// assert_eq!(format.display(f64::NAN).to_string(), "nan");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Round numbers to this many significant digits. If [`None`], numbers
+    /// are not rounded.
+    pub significant_digits: Option<usize>,
+    pub notation: Notation,
+}
+
+/// Notation used to write finite numbers.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Notation {
+    /// Use scientific notation only for very small (magnitude below `1e-4`) or
+    /// very large (magnitude of at least `1e16`) numbers, and fixed notation
+    /// otherwise.
+    #[default]
+    Auto,
+    /// Always use fixed notation e.g. `1200`.
+    Fixed,
+    /// Always use scientific notation e.g. `1.2e3`.
+    Scientific,
+}
+
+impl NumberFormat {
+    /// Create the default number format. Numbers are not rounded and use
+    /// [`Notation::Auto`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::number::NumberFormat;
+    ///
+    /// let format = NumberFormat::new();
+    /// assert_eq!(format.display(1e-7).to_string(), "1e-7");
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Return an object that writes `number` with this format when it is
+    /// displayed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::number::{NumberFormat, Notation};
+    ///
+    /// let format = NumberFormat {
+    ///     significant_digits: Some(2),
+    ///     notation: Notation::Scientific,
+    /// };
+    /// assert_eq!(format!("({},1)", format.display(1234.0)), "(1.2e3,1)");
+    /// ```
+    pub fn display(self, number: f64) -> impl fmt::Display {
+        FormattedNumber {
+            number,
+            format: self,
+        }
+    }
+}
+
+struct FormattedNumber {
+    number: f64,
+    format: NumberFormat,
+}
+
+impl fmt::Display for FormattedNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.number;
+        if number.is_nan() {
+            return write!(f, "nan");
+        }
+        if number.is_infinite() {
+            return write!(f, "{}", if number > 0.0 { "inf" } else { "-inf" });
+        }
+
+        let number = match self.format.significant_digits {
+            Some(digits) => round(number, digits),
+            None => number,
+        };
+        let scientific = match self.format.notation {
+            Notation::Auto => number != 0.0 && !(1e-4..1e16).contains(&number.abs()),
+            Notation::Fixed => false,
+            Notation::Scientific => true,
+        };
+
+        if scientific {
+            write!(f, "{number:e}")
+        } else {
+            write!(f, "{number}")
+        }
+    }
+}
+
+/// Round a finite `number` to the given number of significant digits. At
+/// least one digit is always kept.
+fn round(number: f64, digits: usize) -> f64 {
+    // Going through the decimal representation is the only way to round
+    // without introducing new binary floating-point errors.
+    format!("{:.*e}", digits.max(1) - 1, number)
+        .parse()
+        .expect("scientific representation of a finite f64 is valid")
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,73 @@
+use super::*;
+
+#[test]
+fn number_format_new() {
+    let format = NumberFormat::new();
+    assert!(format.significant_digits.is_none());
+    assert_eq!(format.notation, Notation::Auto);
+}
+
+#[test]
+fn number_format_non_finite() {
+    for notation in [Notation::Auto, Notation::Fixed, Notation::Scientific] {
+        let format = NumberFormat {
+            significant_digits: Some(3),
+            notation,
+        };
+        assert_eq!(format.display(f64::NAN).to_string(), "nan");
+        assert_eq!(format.display(f64::INFINITY).to_string(), "inf");
+        assert_eq!(format.display(f64::NEG_INFINITY).to_string(), "-inf");
+    }
+}
+
+#[test]
+fn number_format_auto() {
+    let format = NumberFormat::new();
+    assert_eq!(format.display(0.0).to_string(), "0");
+    assert_eq!(format.display(-1.5).to_string(), "-1.5");
+    assert_eq!(format.display(0.0001).to_string(), "0.0001");
+    assert_eq!(format.display(0.00001).to_string(), "1e-5");
+    assert_eq!(format.display(1e15).to_string(), "1000000000000000");
+    assert_eq!(format.display(-1e16).to_string(), "-1e16");
+    assert_eq!(format.display(0.1 + 0.2).to_string(), "0.30000000000000004");
+}
+
+#[test]
+fn number_format_fixed() {
+    let format = NumberFormat {
+        significant_digits: None,
+        notation: Notation::Fixed,
+    };
+    assert_eq!(format.display(1e-5).to_string(), "0.00001");
+    assert_eq!(format.display(1e16).to_string(), "10000000000000000");
+}
+
+#[test]
+fn number_format_scientific() {
+    let format = NumberFormat {
+        significant_digits: None,
+        notation: Notation::Scientific,
+    };
+    assert_eq!(format.display(0.0).to_string(), "0e0");
+    assert_eq!(format.display(1200.0).to_string(), "1.2e3");
+    assert_eq!(format.display(-0.05).to_string(), "-5e-2");
+}
+
+#[test]
+fn number_format_significant_digits() {
+    let format = NumberFormat {
+        significant_digits: Some(3),
+        notation: Notation::Auto,
+    };
+    assert_eq!(format.display(0.1 + 0.2).to_string(), "0.3");
+    assert_eq!(format.display(1.23456).to_string(), "1.23");
+    assert_eq!(format.display(-4.56789).to_string(), "-4.57");
+    assert_eq!(format.display(99999.0).to_string(), "100000");
+    assert_eq!(format.display(1.23456e-8).to_string(), "1.23e-8");
+
+    let format = NumberFormat {
+        significant_digits: Some(0),
+        notation: Notation::Auto,
+    };
+    assert_eq!(format.display(3.7).to_string(), "4");
+}
@@ -0,0 +1,237 @@
+use std::fmt;
+
+// Only imported for documentation. If you notice that this is no longer the
+// case, please change it.
+#[allow(unused_imports)]
+use crate::Picture;
+
+/// Preamble of the standalone LaTeX document of a [`Picture`].
+///
+/// A [`Preamble`] controls everything written before `\begin{document}`:
+///
+/// ```text
+/// \documentclass[ClassOptions]{standalone}
+/// \usepackage{pgfplots}
+/// \usepackage{Package}
+/// \usepgfplotslibrary{PgfplotsLibraries}
+/// \usetikzlibrary{TikzLibraries}
+/// \pgfplotsset{compat=Compat}
+/// % arbitrary lines
+/// ```
+///
+/// Only the non-empty parts are written. The default preamble loads only the
+/// `pgfplots` package, and sets the compatibility level to
+/// [`DEFAULT_COMPAT`] to avoid the backwards compatibility mode of PGFPlots.
+///
+/// # Examples
+///
+/// ```
+/// use pgfplots::Picture;
+///
+/// let mut picture = Picture::new();
+/// picture.preamble.set_compat("1.18");
+/// picture.preamble.add_pgfplots_library("fillbetween");
+/// ```
+#[derive(Clone, Debug)]
+pub struct Preamble {
+    class_options: Vec<String>,
+    packages: Vec<String>,
+    pgfplots_libraries: Vec<String>,
+    tikz_libraries: Vec<String>,
+    compat: Option<String>,
+    lines: Vec<String>,
+}
+
+/// Compatibility level of a new [`Preamble`]. This is the oldest level that
+/// enables all the features used by this crate (available since PGFPlots 1.16,
+/// released in 2018), so that figures compile with older TeX distributions.
+pub const DEFAULT_COMPAT: &str = "1.16";
+
+impl Default for Preamble {
+    fn default() -> Self {
+        Preamble {
+            class_options: Vec::new(),
+            packages: Vec::new(),
+            pgfplots_libraries: Vec::new(),
+            tikz_libraries: Vec::new(),
+            compat: Some(String::from(DEFAULT_COMPAT)),
+            lines: Vec::new(),
+        }
+    }
+}
+
+impl fmt::Display for Preamble {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\\documentclass")?;
+        if !self.class_options.is_empty() {
+            write!(f, "[{}]", self.class_options.join(","))?;
+        }
+        writeln!(f, "{{standalone}}")?;
+
+        writeln!(f, "\\usepackage{{pgfplots}}")?;
+        for package in self.packages.iter() {
+            writeln!(f, "\\usepackage{{{package}}}")?;
+        }
+        if !self.pgfplots_libraries.is_empty() {
+            writeln!(
+                f,
+                "\\usepgfplotslibrary{{{}}}",
+                self.pgfplots_libraries.join(",")
+            )?;
+        }
+        if !self.tikz_libraries.is_empty() {
+            writeln!(f, "\\usetikzlibrary{{{}}}", self.tikz_libraries.join(","))?;
+        }
+        if let Some(compat) = &self.compat {
+            writeln!(f, "\\pgfplotsset{{compat={compat}}}")?;
+        }
+        for line in self.lines.iter() {
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Preamble {
+    /// Create a new preamble that only loads the `pgfplots` package and sets
+    /// the compatibility level to [`DEFAULT_COMPAT`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::preamble::Preamble;
+    ///
+    /// let preamble = Preamble::new();
+    /// assert_eq!(
+    /// r#"\documentclass{standalone}
+    /// \usepackage{pgfplots}
+    /// \pgfplotsset{compat=1.16}
+    /// "#,
+    /// preamble.to_string());
+    /// ```
+    pub fn new() -> Self {
+        Default::default()
+    }
+    /// Add an option to the `standalone` document class e.g. `border=5pt`. This
+    /// has no effect if the option was already added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::preamble::Preamble;
+    ///
+    /// let mut preamble = Preamble::new();
+    /// preamble.add_class_option("border=5pt");
+    /// ```
+    pub fn add_class_option<S: Into<String>>(&mut self, option: S) {
+        push_unique(&mut self.class_options, option.into());
+    }
+    /// Load a LaTeX package e.g. `amsmath`. This has no effect if the package
+    /// was already added. Use [`Preamble::add_line`] to load a package with
+    /// options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::preamble::Preamble;
+    ///
+    /// let mut preamble = Preamble::new();
+    /// preamble.add_package("amsmath");
+    /// ```
+    pub fn add_package<S: Into<String>>(&mut self, package: S) {
+        let package = package.into();
+        // PGFPlots is always loaded.
+        if package != "pgfplots" {
+            push_unique(&mut self.packages, package);
+        }
+    }
+    /// Load a PGFPlots library e.g. `fillbetween`, `groupplots`, `statistics`,
+    /// or `polar`. This has no effect if the library was already added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::preamble::Preamble;
+    ///
+    /// let mut preamble = Preamble::new();
+    /// preamble.add_pgfplots_library("statistics");
+    /// ```
+    pub fn add_pgfplots_library<S: Into<String>>(&mut self, library: S) {
+        push_unique(&mut self.pgfplots_libraries, library.into());
+    }
+    /// Load a Ti*k*Z library e.g. `patterns`. This has no effect if the library
+    /// was already added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::preamble::Preamble;
+    ///
+    /// let mut preamble = Preamble::new();
+    /// preamble.add_tikz_library("patterns");
+    /// ```
+    pub fn add_tikz_library<S: Into<String>>(&mut self, library: S) {
+        push_unique(&mut self.tikz_libraries, library.into());
+    }
+    /// Set the PGFPlots compatibility level e.g. `1.18` or `newest`. This will
+    /// overwrite any previous compatibility level.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::preamble::Preamble;
+    ///
+    /// let mut preamble = Preamble::new();
+    /// preamble.set_compat("1.18");
+    /// ```
+    pub fn set_compat<S: Into<String>>(&mut self, compat: S) {
+        self.compat = Some(compat.into());
+    }
+    /// Add an arbitrary line of LaTeX code at the end of the preamble. Lines
+    /// are written verbatim in the same order they were added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgfplots::preamble::Preamble;
+    ///
+    /// let mut preamble = Preamble::new();
+    /// preamble.add_line("\\usepackage[T1]{fontenc}");
+    /// ```
+    pub fn add_line<S: Into<String>>(&mut self, line: S) {
+        self.lines.push(line.into());
+    }
+    /// Raise the compatibility level to at least `version` e.g. `1.14`. This
+    /// has no effect if the current compatibility level is already at least
+    /// `version`, or if it is not a plain `major.minor` version (e.g. `newest`).
+    pub(crate) fn require_compat(&mut self, version: &str) {
+        match &self.compat {
+            None => self.set_compat(version),
+            Some(current) => {
+                if let (Some(current), Some(required)) =
+                    (parse_version(current), parse_version(version))
+                {
+                    if current < required {
+                        self.set_compat(version);
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Parse a `major.minor` version into a tuple that compares numerically.
+fn parse_version(version: &str) -> Option<(u32, u32)> {
+    let (major, minor) = version.split_once('.')?;
+    Some((major.parse().ok()?, minor.parse().ok()?))
+}
+
+fn push_unique(values: &mut Vec<String>, value: String) {
+    if !values.contains(&value) {
+        values.push(value);
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,117 @@
+use super::*;
+
+#[test]
+fn preamble_new() {
+    let preamble = Preamble::new();
+    assert!(preamble.class_options.is_empty());
+    assert!(preamble.packages.is_empty());
+    assert!(preamble.pgfplots_libraries.is_empty());
+    assert!(preamble.tikz_libraries.is_empty());
+    assert_eq!(preamble.compat.as_deref(), Some(DEFAULT_COMPAT));
+    assert!(preamble.lines.is_empty());
+}
+
+#[test]
+fn preamble_add_class_option() {
+    let mut preamble = Preamble::new();
+    preamble.add_class_option("border=5pt");
+    preamble.add_class_option("varwidth");
+    preamble.add_class_option("border=5pt");
+    assert_eq!(preamble.class_options, vec!["border=5pt", "varwidth"]);
+}
+
+#[test]
+fn preamble_add_package() {
+    let mut preamble = Preamble::new();
+    preamble.add_package("amsmath");
+    preamble.add_package("pgfplots");
+    preamble.add_package("siunitx");
+    preamble.add_package("amsmath");
+    assert_eq!(preamble.packages, vec!["amsmath", "siunitx"]);
+}
+
+#[test]
+fn preamble_add_pgfplots_library() {
+    let mut preamble = Preamble::new();
+    preamble.add_pgfplots_library("fillbetween");
+    preamble.add_pgfplots_library("statistics");
+    preamble.add_pgfplots_library("fillbetween");
+    assert_eq!(
+        preamble.pgfplots_libraries,
+        vec!["fillbetween", "statistics"]
+    );
+}
+
+#[test]
+fn preamble_add_tikz_library() {
+    let mut preamble = Preamble::new();
+    preamble.add_tikz_library("patterns");
+    preamble.add_tikz_library("patterns");
+    assert_eq!(preamble.tikz_libraries, vec!["patterns"]);
+}
+
+#[test]
+fn preamble_set_compat() {
+    let mut preamble = Preamble::new();
+    preamble.set_compat("1.7");
+    assert_eq!(preamble.compat.as_deref(), Some("1.7"));
+    preamble.set_compat("newest");
+    assert_eq!(preamble.compat.as_deref(), Some("newest"));
+}
+
+#[test]
+fn preamble_add_line() {
+    let mut preamble = Preamble::new();
+    preamble.add_line("\\usepackage[T1]{fontenc}");
+    preamble.add_line("\\usepackage[T1]{fontenc}");
+    assert_eq!(preamble.lines.len(), 2);
+}
+
+#[test]
+fn preamble_to_string() {
+    let mut preamble = Preamble::new();
+    assert_eq!(
+        preamble.to_string(),
+        "\\documentclass{standalone}\n\\usepackage{pgfplots}\n\\pgfplotsset{compat=1.16}\n"
+    );
+
+    preamble.add_class_option("border=5pt");
+    preamble.add_class_option("varwidth");
+    preamble.add_package("amsmath");
+    preamble.add_package("siunitx");
+    preamble.add_pgfplots_library("fillbetween");
+    preamble.add_pgfplots_library("groupplots");
+    preamble.add_tikz_library("patterns");
+    preamble.set_compat("1.18");
+    preamble.add_line("\\usepackage[T1]{fontenc}");
+    assert_eq!(
+        preamble.to_string(),
+        r#"\documentclass[border=5pt,varwidth]{standalone}
+\usepackage{pgfplots}
+\usepackage{amsmath}
+\usepackage{siunitx}
+\usepgfplotslibrary{fillbetween,groupplots}
+\usetikzlibrary{patterns}
+\pgfplotsset{compat=1.18}
+\usepackage[T1]{fontenc}
+"#
+    );
+}
+
+#[test]
+fn preamble_require_compat() {
+    let mut preamble = Preamble::new();
+    preamble.require_compat("1.14");
+    assert_eq!(preamble.compat.as_deref(), Some(DEFAULT_COMPAT));
+    preamble.set_compat("1.7");
+    preamble.require_compat("1.14");
+    assert_eq!(preamble.compat.as_deref(), Some("1.14"));
+    preamble.require_compat("1.9");
+    assert_eq!(preamble.compat.as_deref(), Some("1.14"));
+    preamble.require_compat("1.18");
+    assert_eq!(preamble.compat.as_deref(), Some("1.18"));
+
+    preamble.set_compat("newest");
+    preamble.require_compat("1.14");
+    assert_eq!(preamble.compat.as_deref(), Some("newest"));
+}
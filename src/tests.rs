@@ -1,5 +1,6 @@
 use super::*;
-use crate::axis::plot::Plot2D;
+use crate::axis::plot::{coordinate::PointMeta, error_band::ErrorBand, Plot2D, PlotKey};
+use crate::axis::AxisKey;
 
 // This test is here only to let us know if we added an enum variant
 // but we forgot to add unit tests for it
@@ -14,7 +15,6 @@ fn picture_keys_tested() {
     match picture_key {
         PictureKey::Custom(_) => (),
     }
-    assert!(true);
 }
 
 #[test]
@@ -51,6 +51,7 @@ fn picture_standalone_string() {
     assert_eq!(
         r#"\documentclass{standalone}
 \usepackage{pgfplots}
+\pgfplotsset{compat=1.16}
 \begin{document}
 \begin{tikzpicture}
 \end{tikzpicture}
@@ -83,7 +84,298 @@ fn picture_to_string() {
 
     picture.add_key(PictureKey::Custom(String::from("baseline")));
     picture.add_key(PictureKey::Custom(String::from("scale=2")));
-    axis.plots.push(Plot2D::new());
+    axis.plots.push(Plot2D::new().into());
     picture.axes.push(axis.clone());
     assert_eq!(picture.to_string(), "\\begin{tikzpicture}[\n\tbaseline,\n\tscale=2,\n]\n\\begin{axis}\n\\end{axis}\n\\begin{axis}\n\t\\addplot[] coordinates {\n\t};\n\\end{axis}\n\\end{tikzpicture}");
 }
+
+#[test]
+fn show_pdf_error_source() {
+    use std::error::Error;
+
+    let error: ShowPdfError = CompileError::new(None, None).into();
+    assert!(matches!(error, ShowPdfError::Compile(_)));
+    assert_eq!(error.to_string(), "LaTeX compilation error");
+    assert_eq!(
+        error.source().unwrap().to_string(),
+        "LaTeX compilation failed"
+    );
+
+    let error: ShowPdfError = std::io::Error::other("disk full").into();
+    assert!(matches!(error, ShowPdfError::Write(_)));
+    assert_eq!(error.source().unwrap().to_string(), "disk full");
+}
+
+#[test]
+fn picture_standalone_string_preamble() {
+    let mut picture = Picture::new();
+    picture.preamble.set_compat("1.18");
+    picture.preamble.add_pgfplots_library("polar");
+    assert_eq!(
+        r#"\documentclass{standalone}
+\usepackage{pgfplots}
+\usepgfplotslibrary{polar}
+\pgfplotsset{compat=1.18}
+\begin{document}
+\begin{tikzpicture}
+\end{tikzpicture}
+\end{document}"#,
+        picture.standalone_string()
+    );
+}
+
+#[test]
+fn picture_add_requirements() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Custom(String::from("fill=gray!20")));
+    let mut axis = Axis::new();
+    axis.add_key(AxisKey::Custom(String::from("hide axis")));
+    axis.plots.push(plot.into());
+    let mut picture = Picture::new();
+    picture.add_key(PictureKey::Custom(String::from("baseline")));
+    picture.axes.push(axis);
+
+    // Custom keys never add requirements to the preamble.
+    let mut preamble = Preamble::new();
+    picture.add_requirements(&mut preamble);
+    assert_eq!(preamble.to_string(), Preamble::new().to_string());
+
+    // Requirements are added after whatever the user added manually.
+    let mut preamble = Preamble::new();
+    preamble.set_compat("1.18");
+    picture.add_requirements(&mut preamble);
+    assert_eq!(
+        preamble.to_string(),
+        "\\documentclass{standalone}\n\\usepackage{pgfplots}\n\\pgfplotsset{compat=1.18}\n"
+    );
+}
+
+#[test]
+fn picture_export() {
+    let directory = tempfile::tempdir().unwrap();
+    let directory = directory.path().join("figures");
+
+    let mut line = Plot2D::new();
+    line.coordinates.push((1.0, -1.0).into());
+    line.coordinates.push((2.0, -2.0).into());
+    let mut points = Plot2D::new();
+    points.add_key(PlotKey::Custom(String::from("only marks")));
+    points.coordinates.push((1.0, 2.0, None, Some(0.5)).into());
+    points.coordinates.push((3.0, 4.0).into());
+    let mut axis = Axis::new();
+    axis.plots.push(line.into());
+    axis.plots.push(points.into());
+    let mut picture = Picture::new();
+    picture.add_key(PictureKey::Custom(String::from("baseline")));
+    picture.axes.push(Axis::new());
+    picture.axes.push(axis);
+
+    picture.export(&directory, "figure").unwrap();
+
+    let prefix = directory.to_string_lossy().replace('\\', "/");
+    assert_eq!(
+        std::fs::read_to_string(directory.join("figure.tex")).unwrap(),
+        format!("\\begin{{tikzpicture}}[\n\tbaseline,\n]\n\\begin{{axis}}\n\\end{{axis}}\n\\begin{{axis}}\n\t\\addplot[] table {{{prefix}/figure-1-0.dat}};\n\t\\addplot[\n\t\tonly marks,\n\t] table[x error=ex, y error=ey] {{{prefix}/figure-1-1.dat}};\n\\end{{axis}}\n\\end{{tikzpicture}}")
+    );
+    assert_eq!(
+        std::fs::read_to_string(directory.join("figure-1-0.dat")).unwrap(),
+        "x y\n1 -1\n2 -2\n"
+    );
+    assert_eq!(
+        std::fs::read_to_string(directory.join("figure-1-1.dat")).unwrap(),
+        "x y ex ey\n1 2 0 0.5\n3 4 0 0\n"
+    );
+}
+
+#[test]
+fn picture_write_standalone() {
+    let mut picture = Picture::new();
+    picture.preamble.set_compat("1.18");
+    let mut axis = Axis::new();
+    axis.set_title("Title");
+    let mut plot = Plot2D::new();
+    plot.coordinates = (0..10).map(|i| (i as f64, i as f64).into()).collect();
+    axis.plots.push(plot.into());
+    picture.axes.push(axis);
+
+    let mut buffer = Vec::new();
+    picture.write_standalone(&mut buffer).unwrap();
+    assert_eq!(
+        picture.standalone_string(),
+        String::from_utf8(buffer).unwrap()
+    );
+}
+
+#[test]
+fn standalone_to_string() {
+    let mut plot = Plot2D::new();
+    plot.add_key(PlotKey::Custom(String::from("smooth")));
+    let mut axis = Axis::new();
+    axis.plots.push(plot.clone().into());
+    let mut picture = Picture::new();
+    picture.axes.push(axis.clone());
+
+    assert_eq!(
+        picture.standalone_string(),
+        Standalone::Axis(&axis).to_string()
+    );
+    assert_eq!(
+        picture.standalone_string(),
+        Standalone::Plot2D(&plot).to_string()
+    );
+}
+
+#[test]
+fn standalone_plot_2d_legend() {
+    let mut plot = Plot2D::new();
+    plot.legend = Some(String::from("data"));
+    assert!(Standalone::Plot2D(&plot)
+        .to_string()
+        .contains("\t};\n\t\\addlegendentry{data}\n\\end{axis}"));
+}
+
+#[test]
+fn picture_export_legend() {
+    let mut plot = Plot2D::new();
+    plot.legend = Some(String::from("data"));
+    let mut axis = Axis::new();
+    axis.plots.push(plot.into());
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    let directory = tempfile::tempdir().unwrap();
+    picture.export(directory.path(), "legend").unwrap();
+
+    let snippet = std::fs::read_to_string(directory.path().join("legend.tex")).unwrap();
+    let path = format!("{}/legend-0-0.dat", directory.path().to_string_lossy());
+    assert!(snippet.contains(&format!(
+        " table {{{path}}};\n\t\\addlegendentry{{data}}\n\\end{{axis}}"
+    )));
+}
+
+#[test]
+fn picture_number_format() {
+    let mut plot = Plot2D::new();
+    plot.coordinates.push((1.0 / 3.0, 2.0 / 3.0).into());
+    let mut axis = Axis::new();
+    axis.plots.push(plot.clone().into());
+    plot.number_format = Some(NumberFormat::new());
+    axis.plots.push(plot.into());
+    let mut picture = Picture::new();
+    picture.number_format.significant_digits = Some(3);
+    picture.axes.push(axis);
+
+    assert_eq!(
+        picture.to_string(),
+        "\\begin{tikzpicture}\n\\begin{axis}\n\t\\addplot[] coordinates {\n\t\t(0.333,0.667)\n\t};\n\t\\addplot[] coordinates {\n\t\t(0.3333333333333333,0.6666666666666666)\n\t};\n\\end{axis}\n\\end{tikzpicture}"
+    );
+}
+
+#[test]
+fn picture_export_3d() {
+    let mut plot = Plot3D::new();
+    plot.coordinates.push((1.0, 2.0, 3.0).into());
+    let mut axis = Axis::new();
+    axis.plots.push(plot.into());
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    let directory = tempfile::tempdir().unwrap();
+    picture.export(directory.path(), "surface").unwrap();
+
+    let table = std::fs::read_to_string(directory.path().join("surface-0-0.dat")).unwrap();
+    assert_eq!(table, "x y z\n1 2 3\n");
+    let snippet = std::fs::read_to_string(directory.path().join("surface.tex")).unwrap();
+    assert!(snippet.contains("\t\\addplot3[] table {"));
+}
+
+#[test]
+fn picture_export_point_meta() {
+    let mut plot = Plot2D::new();
+    plot.coordinates.push((1.0, 2.0, Some(0.5), None).into());
+    plot.coordinates[0].point_meta = Some(PointMeta::Numeric(3.0));
+    let mut axis = Axis::new();
+    axis.plots.push(plot.into());
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    let directory = tempfile::tempdir().unwrap();
+    picture.export(directory.path(), "scatter").unwrap();
+
+    let table = std::fs::read_to_string(directory.path().join("scatter-0-0.dat")).unwrap();
+    assert_eq!(table, "x y ex ey meta\n1 2 0.5 0 3\n");
+    let snippet = std::fs::read_to_string(directory.path().join("scatter.tex")).unwrap();
+    assert!(snippet.contains("\t\\addplot[] table[x error=ex, y error=ey, meta=meta] {"));
+}
+
+#[test]
+fn picture_export_asymmetric_errors() {
+    let mut plot = Plot2D::new();
+    plot.coordinates.push((1.0, 2.0).into());
+    plot.coordinates[0].error_x_minus = Some(0.5);
+    let mut axis = Axis::new();
+    axis.plots.push(plot.into());
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    let directory = tempfile::tempdir().unwrap();
+    picture.export(directory.path(), "errors").unwrap();
+
+    let table = std::fs::read_to_string(directory.path().join("errors-0-0.dat")).unwrap();
+    assert_eq!(table, "x y exp exm eyp eym\n1 2 0 0.5 0 0\n");
+    let snippet = std::fs::read_to_string(directory.path().join("errors.tex")).unwrap();
+    assert!(snippet.contains(
+        "\t\\addplot[] table[x error plus=exp, x error minus=exm, y error plus=eyp, y error minus=eym] {"
+    ));
+}
+
+#[test]
+fn picture_export_fill_between() {
+    let mut axis = Axis::new();
+    axis.plots.push(Plot2D::new().into());
+    axis.plots.push(Plot2D::new().into());
+    axis.fill_between(0, 1);
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    let directory = tempfile::tempdir().unwrap();
+    picture.export(directory.path(), "band").unwrap();
+
+    assert!(directory.path().join("band-0-1.dat").exists());
+    assert!(!directory.path().join("band-0-2.dat").exists());
+    let snippet = std::fs::read_to_string(directory.path().join("band.tex")).unwrap();
+    assert!(snippet.contains("\t\\addplot[] fill between[of=plot0 and plot1];\n"));
+}
+
+#[test]
+fn picture_fill_between_requirements() {
+    let mut axis = Axis::new();
+    axis.plots.push(Plot2D::new().into());
+    axis.plots.push(Plot2D::new().into());
+    axis.fill_between(0, 1);
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    assert!(picture
+        .standalone_string()
+        .contains("\\usepgfplotslibrary{fillbetween}\n"));
+}
+
+#[test]
+fn picture_export_error_band() {
+    let mut axis = Axis::new();
+    axis.plots
+        .push(ErrorBand::from_deviation(vec![0.0], vec![1.0], &[0.5]).into());
+    let mut picture = Picture::new();
+    picture.axes.push(axis);
+
+    let directory = tempfile::tempdir().unwrap();
+    picture.export(directory.path(), "band").unwrap();
+
+    let table = std::fs::read_to_string(directory.path().join("band-0-0.dat")).unwrap();
+    assert_eq!(table, "x y bx by\n0 1 0 0.5\nnan nan 0 1.5\n");
+    let snippet = std::fs::read_to_string(directory.path().join("band.tex")).unwrap();
+    let path = format!("{}/band-0-0.dat", directory.path().to_string_lossy());
+    assert!(snippet.contains(&format!("\t] table[x=bx, y=by] {{{path}}};\n")));
+    assert!(snippet.contains(&format!("\t\\addplot+[] table[x=x, y=y] {{{path}}};\n")));
+}